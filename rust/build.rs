@@ -0,0 +1,165 @@
+//! Discovers solution modules under `src/<year>/<day>/mod.rs` and generates the
+//! year modules that `src/lib.rs` includes and the registry that
+//! `src/registry.rs` includes, so adding a day directory is enough to make it
+//! runnable. Each day module provides `parse(&str) -> Result<Input>` and
+//! `part1`/`part2(&Input) -> Result<Answer>`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+struct Day {
+    day: u32,
+    dir: PathBuf,
+    has_visualizer: bool,
+}
+
+struct Year {
+    year: u32,
+    days: Vec<Day>,
+}
+
+/// Numbered subdirectories of `dir`, sorted by number.
+fn numbered_dirs(dir: &Path) -> Vec<(u32, PathBuf)> {
+    let mut dirs: Vec<(u32, PathBuf)> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir())
+                .filter_map(|path| {
+                    let n = path.file_name()?.to_str()?.parse().ok()?;
+                    Some((n, path))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    dirs.sort_by_key(|(n, _)| *n);
+    dirs
+}
+
+fn discover(src: &Path) -> Vec<Year> {
+    numbered_dirs(src)
+        .into_iter()
+        .filter(|(year, _)| *year >= 2015)
+        .map(|(year, year_dir)| {
+            let days = numbered_dirs(&year_dir)
+                .into_iter()
+                .filter(|(day, dir)| (1..=25).contains(day) && dir.join("mod.rs").is_file())
+                .map(|(day, dir)| Day {
+                    day,
+                    has_visualizer: dir.join("visualize.rs").is_file(),
+                    dir,
+                })
+                .collect();
+            Year { year, days }
+        })
+        .filter(|year: &Year| !year.days.is_empty())
+        .collect()
+}
+
+/// `pub mod yearYYYY { pub mod dayN; }` for every discovered day.
+fn generate_solutions(years: &[Year]) -> String {
+    let mut out = String::new();
+
+    for year in years {
+        writeln!(out, "#[allow(dead_code)]").unwrap();
+        writeln!(out, "pub mod year{} {{", year.year).unwrap();
+        for day in &year.days {
+            let path = day.dir.join("mod.rs");
+            writeln!(out, "    #[path = {:?}]", path.display().to_string()).unwrap();
+            writeln!(out, "    pub mod day{};", day.day).unwrap();
+        }
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    out
+}
+
+/// `YEARS`, `get_solution` and `get_visualizer` dispatching to the year modules.
+fn generate_registry(years: &[Year]) -> String {
+    let mut out = String::new();
+
+    let year_list: Vec<String> = years.iter().map(|y| y.year.to_string()).collect();
+    writeln!(out, "/// Every year with at least one day, oldest first").unwrap();
+    writeln!(
+        out,
+        "pub const YEARS: &[u32] = &[{}];\n",
+        year_list.join(", ")
+    )
+    .unwrap();
+
+    writeln!(
+        out,
+        "pub fn get_solution(year: u32, day: u32) -> Option<Solution> {{"
+    )
+    .unwrap();
+    writeln!(out, "    match (year, day) {{").unwrap();
+    for year in years {
+        for day in &year.days {
+            let module = format!("crate::year{}::day{}", year.year, day.day);
+            writeln!(
+                out,
+                "        ({}, {}) => Some(Solution {{",
+                year.year, day.day
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "            parse: |input| Ok(Box::new({}::parse(input)?)),",
+                module
+            )
+            .unwrap();
+            for part in 1..=2 {
+                writeln!(
+                    out,
+                    "            part{p}: |parsed| {m}::part{p}(downcast(parsed)),",
+                    p = part,
+                    m = module
+                )
+                .unwrap();
+            }
+            writeln!(out, "        }}),").unwrap();
+        }
+    }
+    writeln!(out, "        _ => None,").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(
+        out,
+        "pub fn get_visualizer(year: u32, day: u32, part: u32) -> Option<Visualizer> {{"
+    )
+    .unwrap();
+    writeln!(out, "    match (year, day) {{").unwrap();
+    for year in years {
+        for day in year.days.iter().filter(|d| d.has_visualizer) {
+            let module = format!("crate::year{}::day{}::visualize", year.year, day.day);
+            writeln!(
+                out,
+                "        ({}, {}) if {}::PARTS.contains(&part) => Some(|port| Box::pin({}::run_visualizer(port))),",
+                year.year, day.day, module, module
+            )
+            .unwrap();
+        }
+    }
+    writeln!(out, "        _ => None,").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+
+    out
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let src = manifest_dir.join("src");
+
+    // Cargo scans the whole directory, so new day directories trigger a rebuild
+    println!("cargo:rerun-if-changed=src");
+
+    let years = discover(&src);
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("solutions.rs"), generate_solutions(&years)).unwrap();
+    fs::write(out_dir.join("registry.rs"), generate_registry(&years)).unwrap();
+}
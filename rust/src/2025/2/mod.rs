@@ -1,13 +1,18 @@
-use crate::util::get_input;
+use crate::error::Result;
+use crate::solution::Answer;
+
+pub type Input = String;
+
+pub fn parse(input: &str) -> Result<Input> {
+    Ok(input.to_string())
+}
 
 #[allow(unused_variables)]
-pub fn part1() {
-    let input = get_input(2025, 2);
-    println!("Part 1 not implemented yet.");
+pub fn part1(input: &Input) -> Result<Answer> {
+    Ok(Answer::Unimplemented)
 }
 
 #[allow(unused_variables)]
-pub fn part2() {
-    let input = get_input(2025, 2);
-    println!("Part 2 not implemented yet.");
+pub fn part2(input: &Input) -> Result<Answer> {
+    Ok(Answer::Unimplemented)
 }
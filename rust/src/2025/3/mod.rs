@@ -1,14 +1,24 @@
-use crate::util::get_input;
+use crate::error::Result;
+use crate::solution::Answer;
 
-fn calculate_joltage(input: String, n: usize) -> u64 {
-    input
+/// Battery joltage ratings, one bank per line
+pub type Input = Vec<Vec<u64>>;
+
+pub fn parse(input: &str) -> Result<Input> {
+    Ok(input
         .lines()
         .map(|bank| {
-            let batteries: Vec<u64> = bank
-                .chars()
+            bank.chars()
                 .filter_map(|c| c.to_digit(10).map(|d| d as u64))
-                .collect();
+                .collect()
+        })
+        .collect())
+}
 
+fn calculate_joltage(banks: &Input, n: usize) -> u64 {
+    banks
+        .iter()
+        .map(|batteries| {
             let mut result = 0u64;
             let mut start_idx = 0;
 
@@ -32,20 +42,10 @@ fn calculate_joltage(input: String, n: usize) -> u64 {
         .sum()
 }
 
-#[allow(unused_variables)]
-pub fn part1() {
-    let input = get_input(2025, 3);
-
-    let joltage = calculate_joltage(input, 2);
-
-    println!("Part 1 result: {}", joltage);
+pub fn part1(banks: &Input) -> Result<Answer> {
+    Ok(calculate_joltage(banks, 2).into())
 }
 
-#[allow(unused_variables)]
-pub fn part2() {
-    let input = get_input(2025, 3);
-
-    let joltage = calculate_joltage(input, 12);
-
-    println!("Part 2 result: {}", joltage);
+pub fn part2(banks: &Input) -> Result<Answer> {
+    Ok(calculate_joltage(banks, 12).into())
 }
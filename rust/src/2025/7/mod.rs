@@ -1,4 +1,5 @@
-use crate::util::get_input;
+use crate::error::{Error, Result};
+use crate::solution::Answer;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Tile {
@@ -45,14 +46,28 @@ struct State {
     ignore_collisions: bool,
 }
 
-impl State {
-    fn new(input: &str, ignore_collisions: bool) -> Self {
-        let grid = input
-            .lines()
-            .filter(|line| !line.is_empty())
-            .map(|line| line.chars().map(Tile::from).collect())
-            .collect();
+pub struct Input {
+    grid: Vec<Vec<Tile>>,
+    emitter_x: usize,
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    let emitter_x = input
+        .lines()
+        .find_map(|line| line.find('S'))
+        .ok_or_else(|| Error::malformed(1, 1, "no emitter 'S' found"))?;
+
+    let grid = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.chars().map(Tile::from).collect())
+        .collect();
+
+    Ok(Input { grid, emitter_x })
+}
 
+impl State {
+    fn new(grid: Vec<Vec<Tile>>, ignore_collisions: bool) -> Self {
         Self {
             grid,
             particles: Vec::new(),
@@ -205,42 +220,28 @@ impl State {
     }
 }
 
-pub fn part1() {
-    let input = get_input(2025, 7);
-
-    let emitter_idx = input.find('S').expect("No emitter found");
-    let first_line_len = input.lines().next().unwrap().len();
-    let emitter_x = emitter_idx % (first_line_len + 1); // +1 for newline
-
-    let mut state = State::new(&input, false);
+pub fn part1(input: &Input) -> Result<Answer> {
+    let mut state = State::new(input.grid.clone(), false);
     state.particles.push(Particle {
-        x: emitter_x,
+        x: input.emitter_x,
         y: 1,
         count: 1,
     });
 
     state.run_simulation();
-    state.print();
 
-    println!("{}", state.split_count);
+    Ok(state.split_count.into())
 }
 
-pub fn part2() {
-    let input = get_input(2025, 7);
-
-    let emitter_idx = input.find('S').expect("No emitter found");
-    let first_line_len = input.lines().next().unwrap().len();
-    let emitter_x = emitter_idx % (first_line_len + 1); // +1 for newline
-
-    let mut state = State::new(&input, true);
+pub fn part2(input: &Input) -> Result<Answer> {
+    let mut state = State::new(input.grid.clone(), true);
     state.particles.push(Particle {
-        x: emitter_x,
+        x: input.emitter_x,
         y: 1,
         count: 1,
     });
 
     state.run_simulation();
-    state.print();
 
-    println!("{}", state.split_count + 1);
+    Ok((state.split_count + 1).into())
 }
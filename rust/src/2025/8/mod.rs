@@ -1,6 +1,8 @@
-use crate::util::get_input;
+use crate::error::{parse_field, Error, Result};
+use crate::solution::Answer;
+use crate::util::split_columns;
 
-type JunctionBox = (f64, f64, f64);
+pub type JunctionBox = (f64, f64, f64);
 
 #[inline]
 fn calculate_distance(a: &JunctionBox, b: &JunctionBox) -> f64 {
@@ -75,7 +77,7 @@ fn create_circuits(
     let mut last: Option<(usize, usize)> = None;
 
     // Pre-allocate with exact capacity
-    let total_pairs = n * (n - 1) / 2;
+    let total_pairs = n * n.saturating_sub(1) / 2;
     let mut distances = Vec::with_capacity(total_pairs);
 
     // Calculate all pairwise distances
@@ -110,52 +112,57 @@ fn create_circuits(
     (uf.get_component_sizes(), last)
 }
 
-pub fn part1() {
-    let input = get_input(2025, 8);
+pub type Input = Vec<JunctionBox>;
 
-    let junction_boxes: Vec<JunctionBox> = input
+pub fn parse(input: &str) -> Result<Input> {
+    let junction_boxes = input
         .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let mut coords = line.split(',').map(|s| s.trim().parse::<f64>().unwrap());
-            (
-                coords.next().unwrap(),
-                coords.next().unwrap(),
-                coords.next().unwrap(),
-            )
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            let coords = split_columns(line, ',')
+                .map(|(column, field)| parse_field(i + 1, column, field))
+                .collect::<Result<Vec<f64>>>()?;
+
+            match coords[..] {
+                [x, y, z] => Ok((x, y, z)),
+                _ => Err(Error::malformed(
+                    i + 1,
+                    1,
+                    format!("expected 3 coordinates, found {}", coords.len()),
+                )),
+            }
         })
-        .collect();
+        .collect::<Result<Input>>()?;
+
+    // Circuits are built by connecting pairs of boxes
+    if junction_boxes.len() < 2 {
+        return Err(Error::Invalid(format!(
+            "need at least two junction boxes to connect, found {}",
+            junction_boxes.len()
+        )));
+    }
+    Ok(junction_boxes)
+}
 
-    let (component_sizes, _) = create_circuits(&junction_boxes, 1000);
+pub fn part1(junction_boxes: &Input) -> Result<Answer> {
+    let (component_sizes, _) = create_circuits(junction_boxes, 1000);
 
     // Find three largest components
     let mut sizes = component_sizes;
     sizes.sort_unstable_by(|a, b| b.cmp(a));
 
     let result: usize = sizes.iter().take(3).product();
-    println!("{}", result);
+    Ok(result.into())
 }
 
-pub fn part2() {
-    let input = get_input(2025, 8);
-
-    let junction_boxes: Vec<JunctionBox> = input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let mut coords = line.split(',').map(|s| s.trim().parse::<f64>().unwrap());
-            (
-                coords.next().unwrap(),
-                coords.next().unwrap(),
-                coords.next().unwrap(),
-            )
-        })
-        .collect();
+pub fn part2(junction_boxes: &Input) -> Result<Answer> {
+    let (_, last) = create_circuits(junction_boxes, usize::MAX);
 
-    let (_, last) = create_circuits(&junction_boxes, usize::MAX);
-
-    if let Some((a, b)) = last {
-        let result = (junction_boxes[a].0 as i64) * (junction_boxes[b].0 as i64);
-        println!("{}", result);
-    }
+    // Any two or more boxes end up in one circuit, so only fewer never join a pair
+    let (a, b) = last.ok_or_else(|| {
+        Error::Invalid("need at least two junction boxes to connect".to_string())
+    })?;
+    let result = (junction_boxes[a].0 as i64) * (junction_boxes[b].0 as i64);
+    Ok(result.into())
 }
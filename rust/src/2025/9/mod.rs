@@ -1,4 +1,6 @@
-use crate::util::get_input;
+use crate::error::{parse_field, Error, Result};
+use crate::solution::Answer;
+use crate::util::{get_input, split_columns};
 use rayon::prelude::*;
 
 pub mod visualize;
@@ -82,7 +84,8 @@ impl Line {
 impl Rect {
     #[inline]
     pub fn area(&self) -> u64 {
-        ((self.p2.x - self.p1.x).abs() as u64 + 1) * ((self.p2.y - self.p1.y).abs() as u64 + 1)
+        ((self.p2.x - self.p1.x).unsigned_abs() as u64 + 1)
+            * ((self.p2.y - self.p1.y).unsigned_abs() as u64 + 1)
     }
 }
 
@@ -229,36 +232,39 @@ impl Polygon {
     fn is_point_inside_by_ray_casting(&self, p: Point) -> bool {
         let mut crossings = 0;
         for edge in &self.edges {
-            if edge.crosses_horizontal_at_y(p.y) {
-                if edge.point_left_of_intersection_at_y(p) {
-                    crossings += 1;
-                }
+            if edge.crosses_horizontal_at_y(p.y) && edge.point_left_of_intersection_at_y(p) {
+                crossings += 1;
             }
         }
         crossings % 2 == 1
     }
 }
 
-pub fn parse_input(input: &str) -> Vec<Point> {
+pub type Input = Vec<Point>;
+
+pub fn parse(input: &str) -> Result<Input> {
     input
         .lines()
-        .map(|line| {
-            let mut parts = line.split(',');
-
-            let x = parts.next().unwrap().parse::<i32>().unwrap();
-            let y = parts.next().unwrap().parse::<i32>().unwrap();
-
-            Point { x, y }
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            let coords = split_columns(line, ',')
+                .map(|(column, field)| parse_field(i + 1, column, field))
+                .collect::<Result<Vec<i32>>>()?;
+
+            match coords[..] {
+                [x, y] => Ok(Point { x, y }),
+                _ => Err(Error::malformed(
+                    i + 1,
+                    1,
+                    format!("expected 2 coordinates, found {}", coords.len()),
+                )),
+            }
         })
         .collect()
 }
 
-#[allow(unused_variables)]
-pub fn part1() {
-    let input = get_input(2025, 9);
-
-    let points = parse_input(&input);
-
+pub fn part1(points: &Input) -> Result<Answer> {
     let mut max_area: u64 = 0;
 
     for i in 0..points.len() {
@@ -275,14 +281,10 @@ pub fn part1() {
         }
     }
 
-    println!("Max area: {}", max_area);
+    Ok(max_area.into())
 }
 
-#[allow(unused_variables)]
-pub fn part2() {
-    let input = get_input(2025, 9);
-
-    let points: Vec<Point> = parse_input(&input);
+pub fn part2(points: &Input) -> Result<Answer> {
     let polygon = Polygon::new(points.clone());
 
     // Generate all candidate rectangles with their areas in parallel
@@ -311,14 +313,13 @@ pub fn part2() {
         .map(|(_rect, area)| *area)
         .unwrap_or(0);
 
-    println!("Max contained area: {}", max_area);
+    Ok(max_area.into())
 }
 
 // Public helper to get polygon for visualization
-pub fn get_polygon() -> Polygon {
-    let input = get_input(2025, 9);
-    let points: Vec<Point> = parse_input(&input);
-    Polygon::new(points)
+pub fn get_polygon() -> Result<Polygon> {
+    let input = get_input(2025, 9)?;
+    Ok(Polygon::new(parse(&input)?))
 }
 
 // Generate all candidate rectangles from points
@@ -334,6 +335,6 @@ pub fn generate_candidates(points: &[Point]) -> Vec<(Rect, u64)> {
         }
     }
     // Sort by area descending
-    candidates.sort_unstable_by(|a, b| b.1.cmp(&a.1));
+    candidates.sort_unstable_by_key(|c| std::cmp::Reverse(c.1));
     candidates
 }
@@ -1,4 +1,4 @@
-use super::{generate_candidates, get_polygon, parse_input, Point, Polygon, Rect};
+use super::{generate_candidates, get_polygon, parse, Point, Polygon, Rect};
 use axum::{
     extract::{
         ws::{Message, WebSocket, WebSocketUpgrade},
@@ -15,6 +15,9 @@ use std::time::Duration;
 use tokio::sync::{mpsc, RwLock};
 use tower_http::services::ServeDir;
 
+/// Puzzle parts this visualizer animates
+pub const PARTS: &[u32] = &[2];
+
 // Messages from client to server
 #[derive(Clone, Serialize, Deserialize, Debug)]
 #[serde(tag = "type")]
@@ -123,6 +126,9 @@ struct AppState {
     algorithm_state: Arc<RwLock<AlgorithmState>>,
 }
 
+// Per-worker snapshot of the rectangle currently being checked
+type WorkerStates = Arc<std::sync::Mutex<Vec<Option<(Rect, u64, bool)>>>>;
+
 struct AlgorithmState {
     running: bool,
     paused: bool,
@@ -135,7 +141,7 @@ struct AlgorithmState {
     candidates: Option<Arc<Vec<(Rect, u64)>>>,
     total_checked: Option<Arc<std::sync::atomic::AtomicUsize>>,
     best_area: Option<Arc<std::sync::atomic::AtomicU64>>,
-    worker_states: Option<Arc<std::sync::Mutex<Vec<Option<(Rect, u64, bool)>>>>>,
+    worker_states: Option<WorkerStates>,
 }
 
 impl AlgorithmState {
@@ -511,8 +517,9 @@ async fn run_algorithm(
     println!("[ALG] Using {} worker(s)", num_cores);
 
     // Get all candidates
-    let input = crate::util::get_input(2025, 9);
-    let points: Vec<Point> = parse_input(&input);
+    let points: Vec<Point> = crate::util::get_input(2025, 9)
+        .and_then(|input| parse(&input))
+        .unwrap_or_else(|e| e.exit());
     let candidates = generate_candidates(&points);
 
     println!("[ALG] Total candidates: {}", candidates.len());
@@ -745,11 +752,11 @@ async fn run_algorithm(
     println!("[ALG] Algorithm fully cleaned up and ready for next run");
 }
 
-pub async fn run_visualizer() {
+pub async fn run_visualizer(port: u16) {
     println!("🎄 Advent of Code 2025 - Day 9 Part 2 Visualization 🎄");
     println!("Loading data...");
 
-    let polygon = get_polygon();
+    let polygon = get_polygon().unwrap_or_else(|e| e.exit());
     let polygon_arc = Arc::new(polygon);
 
     let state = AppState {
@@ -759,20 +766,20 @@ pub async fn run_visualizer() {
 
     let app = Router::new()
         .route("/ws", get(ws_handler))
-        .nest_service("/", ServeDir::new("src/2025/9/static"))
+        .nest_service("/", ServeDir::new(crate::util::day_dir(2025, 9).join("static")))
         .with_state(state);
 
-    let addr = "127.0.0.1:3000";
+    let addr = format!("127.0.0.1:{}", port);
     println!("\n🌐 Starting web server at http://{}", addr);
     println!("📊 Open your browser to view the visualization");
     println!("Press Ctrl+C to stop");
 
-    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
+    let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
     axum::serve(listener, app).await.unwrap();
 }
 
 #[tokio::main]
 #[allow(dead_code)]
 async fn main() {
-    run_visualizer().await;
+    run_visualizer(crate::config::get().port()).await;
 }
@@ -0,0 +1,217 @@
+use crate::paths;
+use crate::runner::{RunResult, Status};
+use crate::solution::Answer;
+use crate::util::{example_path, load_json, save_json};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Name of the recorded answers file, kept in the crate directory
+pub const ANSWERS_FILE: &str = "answers.json";
+
+/// Location of the recorded answers.
+pub fn answers_path() -> PathBuf {
+    paths::crate_dir().join(ANSWERS_FILE)
+}
+
+/// Known-correct answers keyed by year, day and part.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerStore {
+    answers: BTreeMap<u32, BTreeMap<u32, BTreeMap<u32, String>>>,
+}
+
+impl AnswerStore {
+    /// Loads the store from `path`, returning an empty store if the file does not exist yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        load_json(path)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        save_json(path, self)
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&str> {
+        self.answers
+            .get(&year)
+            .and_then(|days| days.get(&day))
+            .and_then(|parts| parts.get(&part))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, year: u32, day: u32, part: u32, answer: &Answer) {
+        self.answers
+            .entry(year)
+            .or_default()
+            .entry(day)
+            .or_default()
+            .insert(part, answer.to_string());
+    }
+
+    /// Records every successful result, returning how many answers were written.
+    pub fn record(&mut self, results: &[RunResult]) -> usize {
+        let mut recorded = 0;
+        for result in results {
+            if let (Status::Ok, Some(answer)) = (&result.status, &result.answer) {
+                self.insert(result.year, result.day, result.part, answer);
+                recorded += 1;
+            }
+        }
+        recorded
+    }
+
+    /// All recorded `(day, part)` pairs for `year`, in order.
+    pub fn parts(&self, year: u32) -> Vec<(u32, u32)> {
+        self.answers
+            .get(&year)
+            .map(|days| {
+                days.iter()
+                    .flat_map(|(&day, parts)| parts.keys().map(move |&part| (day, part)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Expected answers for one example input, stored as `debug<N>.expected.json`
+/// next to the example file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ExampleAnswers {
+    parts: BTreeMap<u32, String>,
+}
+
+impl ExampleAnswers {
+    pub fn path(year: u32, day: u32, example: u32) -> PathBuf {
+        example_path(year, day, example).with_extension("expected.json")
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        load_json(path)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        save_json(path, self)
+    }
+
+    pub fn get(&self, part: u32) -> Option<&str> {
+        self.parts.get(&part).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, part: u32, answer: &Answer) {
+        self.parts.insert(part, answer.to_string());
+    }
+}
+
+/// Compares fresh results against the store and prints one line per part.
+/// Returns `true` if every part still produces its recorded answer.
+pub fn print_check(store: &AnswerStore, results: &[RunResult]) -> bool {
+    let mut matching = 0;
+
+    for result in results {
+        let expected = store
+            .get(result.year, result.day, result.part)
+            .unwrap_or_default();
+
+        let verdict = match (&result.status, &result.answer) {
+            (Status::Ok, Some(answer)) if answer.to_string() == expected => "ok".to_string(),
+            (Status::Ok, Some(answer)) => {
+                format!("CHANGED (expected {}, got {})", expected, answer)
+            }
+            (status, _) => format!("FAILED ({})", status.label()),
+        };
+
+        if verdict == "ok" {
+            matching += 1;
+        }
+
+        println!("Day {:>2} part {}: {}", result.day, result.part, verdict);
+    }
+
+    println!("\n{}/{} recorded answers match", matching, results.len());
+    matching == results.len()
+}
+
+/// Compares example answers with the expected ones stored next to the example,
+/// or stores them as the expected answers when `record` is set.
+pub fn check_example(
+    year: u32,
+    day: u32,
+    example: u32,
+    results: &[RunResult],
+    record: bool,
+) -> bool {
+    let path = ExampleAnswers::path(year, day, example);
+    let mut expected = ExampleAnswers::load(&path).unwrap_or_else(|e| {
+        eprintln!("Failed to read {:?}: {}", path, e);
+        std::process::exit(1);
+    });
+
+    let solved: Vec<(u32, &Answer)> = results
+        .iter()
+        .filter_map(|r| match (&r.status, &r.answer) {
+            (Status::Ok, Some(answer)) => Some((r.part, answer)),
+            _ => None,
+        })
+        .collect();
+
+    if record {
+        if solved.is_empty() {
+            eprintln!("No solved parts, nothing to record.");
+            return false;
+        }
+
+        for &(part, answer) in &solved {
+            expected.insert(part, answer);
+        }
+        if let Err(e) = expected.save(&path) {
+            eprintln!("Failed to write {:?}: {}", path, e);
+            std::process::exit(1);
+        }
+        println!("✓ Recorded expected example answers in {:?}", path);
+        return true;
+    }
+
+    let mut passed = true;
+    for (part, answer) in solved {
+        match expected.get(part) {
+            Some(expected) if answer.to_string() == expected => {
+                println!("✓ Example {} part {} passed", example, part);
+            }
+            Some(expected) => {
+                println!(
+                    "✗ Example {} part {} failed: expected {}, got {}",
+                    example, part, expected, answer
+                );
+                passed = false;
+            }
+            None => {
+                println!(
+                    "No expected answer for part {} in {:?}, record one with --record",
+                    part, path
+                );
+            }
+        }
+    }
+    passed
+}
+
+pub fn load_answers(path: &Path) -> AnswerStore {
+    AnswerStore::load(path).unwrap_or_else(|e| {
+        eprintln!("Failed to read {:?}: {}", path, e);
+        std::process::exit(1);
+    })
+}
+
+/// Stores every successful result in the answer store at `path`.
+pub fn record_answers(path: &Path, results: &[RunResult]) {
+    let mut store = load_answers(path);
+    let recorded = store.record(results);
+
+    if let Err(e) = store.save(path) {
+        eprintln!("Failed to write {:?}: {}", path, e);
+        std::process::exit(1);
+    }
+    println!("✓ Recorded {} answer(s) in {:?}", recorded, path);
+}
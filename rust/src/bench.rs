@@ -0,0 +1,302 @@
+use crate::error::Result;
+use crate::solution::{Answer, Solution};
+use crate::util::{input_hash, load_json, now, save_json};
+use crate::{paths, report};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+/// File name of the benchmark history, which lives in the crate directory
+pub const HISTORY_FILE: &str = "bench_history.json";
+
+/// Default number of timed iterations per part
+pub const DEFAULT_ITERATIONS: usize = 100;
+
+/// Default number of untimed runs before timing starts
+pub const DEFAULT_WARMUP: usize = 3;
+
+/// Default slowdown, in percent of the baseline median, reported as a regression
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Timing statistics collected over a number of benchmark iterations.
+#[derive(Debug, Clone)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    /// Computes statistics from raw samples. `samples` must not be empty.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let secs: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        // Nearest-rank percentile
+        let p95_rank = ((0.95 * n as f64).ceil() as usize).clamp(1, n);
+
+        Stats {
+            iterations: n,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            p95: samples[p95_rank - 1],
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    pub fn print(&self) {
+        println!("  min:     {:?}", self.min);
+        println!("  median:  {:?}", self.median);
+        println!("  mean:    {:?}", self.mean);
+        println!("  p95:     {:?}", self.p95);
+        println!("  std dev: {:?}", self.std_dev);
+    }
+}
+
+/// Runs `f` `warmup` times untimed, then `iterations` times timed.
+/// Returns the result of the last run alongside the timing statistics.
+pub fn bench<T>(mut f: impl FnMut() -> T, warmup: usize, iterations: usize) -> (T, Stats) {
+    for _ in 0..warmup {
+        std::hint::black_box(f());
+    }
+
+    let mut samples = Vec::with_capacity(iterations);
+    let mut result = None;
+
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        result = Some(std::hint::black_box(f()));
+        samples.push(start.elapsed());
+    }
+
+    (result.unwrap(), Stats::from_samples(samples))
+}
+
+/// One benchmarked part, as stored in the history file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    /// Commit the benchmark ran on, suffixed with `-dirty` for uncommitted changes
+    pub commit: String,
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub input_hash: String,
+    /// Unix timestamp of the run
+    pub recorded_at: u64,
+    pub iterations: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub mean_ns: u128,
+    pub p95_ns: u128,
+}
+
+impl Entry {
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns as u64)
+    }
+}
+
+/// Every benchmarked run, oldest first.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History {
+    entries: Vec<Entry>,
+}
+
+impl History {
+    /// Loads the history from `path`, returning an empty history if the file does not exist yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        load_json(path)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        save_json(path, self)
+    }
+
+    pub fn push(&mut self, entry: Entry) {
+        self.entries.push(entry);
+    }
+
+    /// The latest run of the same part on the same input from another commit,
+    /// or from `commit` specifically if one is given.
+    pub fn baseline(&self, entry: &Entry, commit: Option<&str>) -> Option<&Entry> {
+        self.entries.iter().rev().find(|e| {
+            e.year == entry.year
+                && e.day == entry.day
+                && e.part == entry.part
+                && e.input_hash == entry.input_hash
+                && match commit {
+                    Some(commit) => e.commit.starts_with(commit),
+                    None => e.commit != entry.commit,
+                }
+        })
+    }
+}
+
+/// Identifies the checked out commit, or "unknown" outside a git repository.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        // The history is keyed by the commit of this repository, not of the working directory
+        Command::new("git")
+            .args(args)
+            .current_dir(paths::crate_dir())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain"]) {
+            Some(changes) if !changes.is_empty() => format!("{}-dirty", hash),
+            _ => hash,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+/// Relative change of `current` against `baseline`, in percent.
+pub fn change_percent(baseline: Duration, current: Duration) -> f64 {
+    let baseline = baseline.as_secs_f64();
+    if baseline == 0.0 {
+        return 0.0;
+    }
+    (current.as_secs_f64() - baseline) / baseline * 100.0
+}
+
+/// Settings for a benchmark run.
+pub struct Options {
+    pub warmup: usize,
+    pub iterations: usize,
+    /// Compare medians with the stored baseline and flag regressions
+    pub compare: bool,
+    /// Commit to compare against instead of the latest other one
+    pub baseline: Option<String>,
+    /// Slowdown in percent that counts as a regression
+    pub threshold: f64,
+}
+
+/// Benchmarks the parser and each part, appends the part timings to the
+/// history file and optionally compares them with the stored baseline.
+/// Returns `false` if a part regressed, or an error if the input does not parse.
+pub fn run(
+    year: u32,
+    day: u32,
+    solution: &Solution,
+    input: &str,
+    parts: &[u32],
+    options: &Options,
+) -> Result<bool> {
+    let (warmup, iterations) = (options.warmup, options.iterations);
+    let (parsed, parse_stats) = bench(|| (solution.parse)(input), warmup, iterations);
+    let parsed = parsed?;
+
+    let mut part_stats: Vec<(u32, Answer, Stats)> = Vec::new();
+    for &part in parts {
+        let (answer, stats) = bench(|| solution.solve(part, &parsed), warmup, iterations);
+        part_stats.push((part, answer?, stats));
+    }
+
+    for (part, answer, _) in &part_stats {
+        report::print_answer(*part, answer);
+    }
+
+    println!(
+        "\nBenchmark ({} iterations, {} warmup):",
+        parse_stats.iterations, warmup
+    );
+    println!("Parse:");
+    parse_stats.print();
+    for (part, _, stats) in &part_stats {
+        println!("Part {}:", part);
+        stats.print();
+    }
+
+    let history_path = paths::crate_dir().join(HISTORY_FILE);
+    let mut history = History::load(&history_path).unwrap_or_else(|e| {
+        eprintln!("Failed to read {:?}: {}", history_path, e);
+        std::process::exit(1);
+    });
+
+    let commit = current_commit();
+    let input_hash = input_hash(input);
+    let recorded_at = now();
+    let entries: Vec<Entry> = part_stats
+        .iter()
+        .filter(|(_, answer, _)| *answer != Answer::Unimplemented)
+        .map(|(part, _, stats)| Entry {
+            commit: commit.clone(),
+            year,
+            day,
+            part: *part,
+            input_hash: input_hash.clone(),
+            recorded_at,
+            iterations: stats.iterations,
+            min_ns: stats.min.as_nanos(),
+            median_ns: stats.median.as_nanos(),
+            mean_ns: stats.mean.as_nanos(),
+            p95_ns: stats.p95.as_nanos(),
+        })
+        .collect();
+
+    let mut passed = true;
+    if options.compare {
+        println!(
+            "\nCompared with the baseline (threshold {}%):",
+            options.threshold
+        );
+        for entry in &entries {
+            let Some(baseline) = history.baseline(entry, options.baseline.as_deref()) else {
+                println!(
+                    "  Part {}: no baseline from another commit on this input yet",
+                    entry.part
+                );
+                continue;
+            };
+
+            let change = change_percent(baseline.median(), entry.median());
+            let verdict = if change > options.threshold {
+                passed = false;
+                "✗ REGRESSION"
+            } else if change < -options.threshold {
+                "✓ faster"
+            } else {
+                "✓ ok"
+            };
+            println!(
+                "  Part {}: median {:?} vs {:?} at {} ({:+.1}%) {}",
+                entry.part,
+                entry.median(),
+                baseline.median(),
+                baseline.commit,
+                change,
+                verdict
+            );
+        }
+    }
+
+    for entry in entries {
+        history.push(entry);
+    }
+    if let Err(e) = history.save(&history_path) {
+        eprintln!("Failed to write {:?}: {}", history_path, e);
+    }
+
+    Ok(passed)
+}
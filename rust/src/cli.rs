@@ -0,0 +1,264 @@
+use crate::error::{Error, Result};
+use crate::{config, http, registry, report, setup};
+use clap::builder::RangedI64ValueParser;
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Command-line interface of the `aoc` binary. Arguments shared between
+/// subcommands are defined once here, together with their validation.
+#[derive(Parser, Debug)]
+#[command(name = "aoc", author, version)]
+#[command(about = "Run, benchmark, visualize and submit Advent of Code solutions")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+
+    /// Directory holding <year>/<day>/input.txt, overriding AOC_DATA_DIR and aoc.toml
+    #[arg(long, value_name = "DIR", global = true)]
+    pub data_dir: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Solve a day, or every implemented day with --all
+    Run(RunArgs),
+    /// Re-run every recorded answer and flag any that changed
+    Check {
+        #[command(flatten)]
+        year: YearArg,
+
+        #[command(flatten)]
+        timeout: TimeoutArg,
+    },
+    /// Benchmark a day and record the timings in the benchmark history
+    Bench(BenchArgs),
+    /// Submit the computed answer to adventofcode.com
+    Submit {
+        #[command(flatten)]
+        target: DayArgs,
+
+        /// Part number (1 or 2)
+        #[arg(short, long, default_value_t = 1, value_parser = part_parser())]
+        part: u32,
+
+        /// Base URL of the Advent of Code server
+        #[arg(long, env = "AOC_BASE_URL", default_value = http::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Download a day's input, puzzle description and example, and scaffold its solution module
+    #[command(alias = "setup-day")]
+    Setup {
+        #[command(flatten)]
+        target: DayArgs,
+
+        /// Overwrite an existing mod.rs and re-download an existing input
+        #[arg(long)]
+        force: bool,
+
+        /// Scaffold without downloading, leaving an empty input.txt placeholder
+        #[arg(long, conflicts_with = "fetch_only")]
+        offline: bool,
+
+        /// Only download the input and puzzle description, replacing the
+        /// placeholder of an offline setup
+        #[arg(long)]
+        fetch_only: bool,
+
+        /// Base URL of the Advent of Code server
+        #[arg(long, env = "AOC_BASE_URL", default_value = http::DEFAULT_BASE_URL)]
+        base_url: String,
+
+        /// Template in templates/ to create mod.rs from, e.g. grid, parse-lines or graph
+        #[arg(long, value_name = "NAME", default_value = setup::DEFAULT_TEMPLATE, conflicts_with = "fetch_only")]
+        template: String,
+    },
+    /// Serve the visualization for a day's part
+    Visualize {
+        #[command(flatten)]
+        target: DayArgs,
+
+        /// Part number (1 or 2)
+        #[arg(short, long, value_parser = part_parser())]
+        part: u32,
+
+        /// Port to serve on, defaulting to `visualize.port` in aoc.toml or 3000
+        #[arg(long)]
+        port: Option<u16>,
+    },
+    /// Rebuild and re-run a day whenever its source or inputs change
+    Watch {
+        #[command(flatten)]
+        target: DayArgs,
+
+        #[command(flatten)]
+        part: PartArg,
+    },
+    /// Move inputs out of src/ into the per-user input cache
+    MigrateInputs {
+        /// Only report what would be moved
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Print a shell completion script to stdout
+    Completions {
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct RunArgs {
+    /// Day number (1-25)
+    #[arg(short, long, value_parser = day_parser(), required_unless_present = "all")]
+    pub day: Option<u32>,
+
+    #[command(flatten)]
+    pub year: YearArg,
+
+    #[command(flatten)]
+    pub part: PartArg,
+
+    /// Read the input from this file instead of the day's input.txt ("-" for stdin)
+    #[arg(short, long, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+
+    /// Run on the example input debug.txt, or debug<N>.txt, and compare with the expected answer
+    #[arg(
+        short,
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        default_missing_value = "1",
+        conflicts_with = "input"
+    )]
+    pub example: Option<u32>,
+
+    /// Run every implemented day and part and print a summary table
+    #[arg(long, conflicts_with_all = ["day", "part", "input", "example"])]
+    pub all: bool,
+
+    /// Store the computed answers as known-correct in answers.json, or as the
+    /// expected example answer when combined with --example
+    #[arg(long, conflicts_with = "input")]
+    pub record: bool,
+
+    #[command(flatten)]
+    pub timeout: TimeoutArg,
+
+    /// Output format for results
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = report::Format::Text,
+        conflicts_with_all = ["example", "record"]
+    )]
+    pub format: report::Format,
+}
+
+#[derive(Args, Debug)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub target: DayArgs,
+
+    #[command(flatten)]
+    pub part: PartArg,
+
+    /// Benchmark on this file instead of the day's input.txt ("-" for stdin)
+    #[arg(short, long, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+
+    /// Timed iterations per part, defaulting to `bench.iterations` in aoc.toml or 100
+    #[arg(short = 'n', long)]
+    pub iterations: Option<usize>,
+
+    /// Untimed warmup runs before benchmarking, defaulting to `bench.warmup` in aoc.toml or 3
+    #[arg(long)]
+    pub warmup: Option<usize>,
+
+    /// Flag parts whose median got slower than the stored baseline
+    #[arg(long)]
+    pub compare: bool,
+
+    /// Compare against this commit instead of the latest other one
+    #[arg(long, value_name = "COMMIT", requires = "compare")]
+    pub baseline: Option<String>,
+
+    /// Slowdown in percent that counts as a regression, defaulting to
+    /// `bench.threshold` in aoc.toml or 10
+    #[arg(long, value_name = "PCT", requires = "compare")]
+    pub threshold: Option<f64>,
+}
+
+/// A single day of a year.
+#[derive(Args, Debug)]
+pub struct DayArgs {
+    /// Day number (1-25)
+    #[arg(short, long, value_parser = day_parser())]
+    pub day: u32,
+
+    #[command(flatten)]
+    pub year: YearArg,
+}
+
+#[derive(Args, Debug)]
+pub struct YearArg {
+    /// Year, defaulting to `year` in aoc.toml or 2025
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(2015..))]
+    pub year: Option<u32>,
+}
+
+impl YearArg {
+    /// The year given on the command line, or the configured default.
+    pub fn get(&self) -> u32 {
+        self.year.unwrap_or_else(|| config::get().year())
+    }
+
+    /// Like [`YearArg::get`], but only for years that have solutions.
+    pub fn supported(&self) -> Result<u32> {
+        let year = self.get();
+        if registry::is_supported(year) {
+            Ok(year)
+        } else {
+            Err(Error::UnsupportedYear(year))
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct PartArg {
+    /// Part number (1 or 2), both parts run if omitted
+    #[arg(short, long, value_parser = part_parser())]
+    pub part: Option<u32>,
+}
+
+impl PartArg {
+    pub fn parts(&self) -> Vec<u32> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct TimeoutArg {
+    /// Give up on a part after this many seconds and report it as timed out
+    /// (defaults to 60 for --all and check, no limit otherwise)
+    #[arg(long, value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..))]
+    pub timeout: Option<u64>,
+}
+
+impl TimeoutArg {
+    pub fn get(&self) -> Option<Duration> {
+        self.timeout.map(Duration::from_secs)
+    }
+}
+
+fn day_parser() -> RangedI64ValueParser<u32> {
+    clap::value_parser!(u32).range(1..=25)
+}
+
+fn part_parser() -> RangedI64ValueParser<u32> {
+    clap::value_parser!(u32).range(1..=2)
+}
@@ -0,0 +1,118 @@
+use crate::bench;
+use crate::error::{Error, Result};
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Name of the project configuration file
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Year used when neither `--year` nor `aoc.toml` names one
+pub const DEFAULT_YEAR: u32 = 2025;
+
+/// Port the visualizer serves on when neither `--port` nor `aoc.toml` names one
+pub const DEFAULT_PORT: u16 = 3000;
+
+/// Settings read from `aoc.toml`. Relative paths are resolved against the
+/// directory containing the file. Command-line flags take priority over all of
+/// them.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Year to run when `--year` is not given
+    pub year: Option<u32>,
+    /// Directory holding `<year>/<day>/input.txt` and the example inputs
+    pub data_dir: Option<PathBuf>,
+    /// File containing the adventofcode.com session cookie, read when
+    /// `AOC_SESSION` is not set
+    pub session_file: Option<PathBuf>,
+    /// Name of this account's directory in the input cache, which unlike the
+    /// session cookie stays the same across logins
+    pub account: Option<String>,
+    pub visualize: VisualizeConfig,
+    pub bench: BenchConfig,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct VisualizeConfig {
+    /// Port the visualizer's web server listens on
+    pub port: Option<u16>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BenchConfig {
+    /// Timed iterations per part
+    pub iterations: Option<usize>,
+    /// Untimed warmup runs before benchmarking
+    pub warmup: Option<usize>,
+    /// Slowdown in percent that counts as a regression
+    pub threshold: Option<f64>,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// The project configuration, loaded on first use. Exits with the error if
+/// `aoc.toml` exists but cannot be read.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(|| Config::load().unwrap_or_else(|e| e.exit()))
+}
+
+impl Config {
+    /// Loads the nearest `aoc.toml`, or the default configuration if there is none.
+    pub fn load() -> Result<Self> {
+        match find() {
+            Some(path) => Self::load_from(&path),
+            None => Ok(Config::default()),
+        }
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let invalid = |reason: String| Error::InvalidConfig {
+            path: path.to_path_buf(),
+            reason,
+        };
+
+        let content = fs::read_to_string(path).map_err(|e| invalid(e.to_string()))?;
+        let mut config: Config = toml::from_str(&content).map_err(|e| invalid(e.to_string()))?;
+
+        let base = path.parent().unwrap_or(Path::new("."));
+        config.data_dir = config.data_dir.map(|dir| base.join(dir));
+        config.session_file = config.session_file.map(|file| base.join(file));
+        Ok(config)
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year.unwrap_or(DEFAULT_YEAR)
+    }
+
+    pub fn port(&self) -> u16 {
+        self.visualize.port.unwrap_or(DEFAULT_PORT)
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.bench.iterations.unwrap_or(bench::DEFAULT_ITERATIONS)
+    }
+
+    pub fn warmup(&self) -> usize {
+        self.bench.warmup.unwrap_or(bench::DEFAULT_WARMUP)
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.bench.threshold.unwrap_or(bench::DEFAULT_THRESHOLD)
+    }
+}
+
+/// The first `aoc.toml` in the working directory or one of its ancestors,
+/// falling back to the one next to the crate manifest.
+pub fn find() -> Option<PathBuf> {
+    let cwd = env::current_dir().ok();
+    cwd.iter()
+        .flat_map(|dir| dir.ancestors())
+        .map(|dir| dir.join(CONFIG_FILE))
+        .chain([Path::new(env!("CARGO_MANIFEST_DIR")).join(CONFIG_FILE)])
+        .find(|path| path.is_file())
+}
@@ -0,0 +1,152 @@
+use crate::registry;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Everything that can go wrong loading an input, parsing it or picking a
+/// solution, as opposed to a solution panicking or giving a wrong answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input file does not exist or cannot be read
+    MissingInput {
+        path: PathBuf,
+        reason: String,
+    },
+    /// A line of the input does not match the puzzle format; `line` and
+    /// `column` are 1-based
+    Malformed {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input parses but does not describe a puzzle that can be solved,
+    /// e.g. it has too few items
+    Invalid(String),
+    UnsupportedYear(u32),
+    /// The day is out of range or has no solution module
+    UnsupportedDay {
+        year: u32,
+        day: u32,
+    },
+    UnsupportedPart(u32),
+    /// `aoc.toml` cannot be read or has unexpected contents
+    InvalidConfig {
+        path: PathBuf,
+        reason: String,
+    },
+}
+
+impl Error {
+    pub fn malformed(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Error::Malformed {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Process exit code: 2 for unsupported arguments or configuration, 3 for
+    /// missing input and 4 for malformed or invalid input. Failing or panicking solutions
+    /// exit with 1.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::UnsupportedYear(_)
+            | Error::UnsupportedDay { .. }
+            | Error::UnsupportedPart(_)
+            | Error::InvalidConfig { .. } => 2,
+            Error::MissingInput { .. } => 3,
+            Error::Malformed { .. } | Error::Invalid(_) => 4,
+        }
+    }
+
+    /// A suggestion for fixing the error, if there is an obvious one.
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Error::MissingInput { .. } => Some(
+                "download the input with `cargo run -- setup --day <day>`, \
+                 point --data-dir or AOC_DATA_DIR at your inputs, \
+                 or pass another file with --input; \
+                 set `account` in aoc.toml to keep using one input cache across logins"
+                    .to_string(),
+            ),
+            Error::UnsupportedDay { year, day } if (1..=25).contains(day) => Some(format!(
+                "scaffold it with `cargo run -- setup --year {} --day {}`",
+                year, day
+            )),
+            _ => None,
+        }
+    }
+
+    /// Prints the error with its hint to stderr and exits with its exit code.
+    pub fn exit(&self) -> ! {
+        eprintln!("error: {}", self);
+        if let Some(hint) = self.hint() {
+            eprintln!("  hint: {}", hint);
+        }
+        std::process::exit(self.exit_code())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingInput { path, reason } => {
+                write!(f, "failed to read input file {:?}: {}", path, reason)
+            }
+            Error::Malformed {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "malformed input at line {}, column {}: {}",
+                line, column, message
+            ),
+            Error::Invalid(message) => write!(f, "invalid input: {}", message),
+            Error::UnsupportedYear(year) => {
+                let years: Vec<String> = registry::YEARS.iter().map(|y| y.to_string()).collect();
+                write!(
+                    f,
+                    "unsupported year: {}. Available years: {}.",
+                    year,
+                    years.join(", ")
+                )
+            }
+            Error::UnsupportedDay { day, .. } if !(1..=25).contains(day) => {
+                write!(f, "invalid day value: {}. Must be between 1 and 25.", day)
+            }
+            Error::UnsupportedDay { year, day } => {
+                write!(f, "solution not implemented for year {}, day {}", year, day)
+            }
+            Error::UnsupportedPart(part) => {
+                write!(f, "invalid part value: {}. Must be 1 or 2.", part)
+            }
+            Error::InvalidConfig { path, reason } => {
+                write!(f, "invalid configuration in {:?}: {}", path, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parses a field that starts at `column` of `line` (both 1-based), ignoring
+/// surrounding whitespace and reporting its position if it does not parse.
+pub fn parse_field<T>(line: usize, column: usize, field: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let trimmed = field.trim_start();
+    let column = column + (field.len() - trimmed.len());
+    let trimmed = trimmed.trim_end();
+
+    if trimmed.is_empty() {
+        return Err(Error::malformed(line, column, "expected a value"));
+    }
+    trimmed
+        .parse()
+        .map_err(|e| Error::malformed(line, column, format!("{:?}: {}", trimmed, e)))
+}
@@ -0,0 +1,69 @@
+//! Just enough HTML handling for the pages served by adventofcode.com, which
+//! keep the puzzle descriptions and answer responses in `<article>` elements.
+
+/// The inner HTML of every `<article>` on the page.
+pub fn articles(html: &str) -> impl Iterator<Item = &str> {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let start = rest.find("<article")?;
+        let open_end = rest[start..].find('>')? + start + 1;
+        let end = rest[open_end..].find("</article>")? + open_end;
+        let article = &rest[open_end..end];
+        rest = &rest[end..];
+        Some(article)
+    })
+}
+
+/// The text of an HTML fragment, with tags removed, entities decoded and any
+/// run of whitespace collapsed into a single space.
+pub fn text(html: &str) -> String {
+    decode_entities(&strip_tags(html))
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Decodes the entities used on the site. `&amp;` goes last so that an
+/// escaped entity such as `&amp;lt;` stays literal.
+pub fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_every_article() {
+        let page = r#"<p>before</p><article class="day-desc"><p>one</p></article><p>between</p><article><p>two</p></article>"#;
+        let articles: Vec<&str> = articles(page).collect();
+        assert_eq!(articles, ["<p>one</p>", "<p>two</p>"]);
+    }
+
+    #[test]
+    fn extracts_text() {
+        assert_eq!(
+            text("<p>That's <em>not</em>\n  the &quot;right&quot; answer &lt;x&gt; &amp;lt;</p>"),
+            "That's not the \"right\" answer <x> &lt;"
+        );
+    }
+}
@@ -0,0 +1,6 @@
+//! Settings shared by every request to adventofcode.com.
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request, as the site asks automated tools to identify themselves
+pub const USER_AGENT: &str = "github.com/marjak93/advent-of-code (rust runner)";
@@ -0,0 +1,25 @@
+//! Advent of Code solutions and the tooling behind the `aoc` binary.
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod config;
+pub mod error;
+pub mod html;
+pub mod http;
+pub mod memory;
+pub mod migrate;
+pub mod paths;
+pub mod puzzle;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod setup;
+pub mod solution;
+pub mod submit;
+pub mod util;
+pub mod watch;
+
+// `pub mod yearYYYY { pub mod dayN; }` for every `src/<year>/<day>/mod.rs`,
+// generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
@@ -1,63 +1,212 @@
-pub mod util;
+use advent_of_code::answers;
+use advent_of_code::cli::{BenchArgs, Cli, Command, RunArgs, TimeoutArg, YearArg};
+use advent_of_code::runner::Status;
+use advent_of_code::{
+    bench, config, migrate, paths, registry, report, runner, setup, submit, util, watch,
+};
+use clap::{CommandFactory, Parser};
+use std::io;
 
-#[path = "2025/mod.rs"]
-pub mod year2025;
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: advent_of_code::memory::CountingAllocator =
+    advent_of_code::memory::CountingAllocator;
 
-use clap::Parser;
-use std::time::Instant;
-
-#[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
-struct Args {
-    /// Day number (1-25)
-    #[arg(short, long)]
-    day: u32,
+fn main() {
+    dotenv::dotenv().ok();
 
-    /// Year
-    #[arg(short, long, default_value_t = 2025)]
-    year: u32,
+    let cli = Cli::parse();
+    paths::init_data_dir(cli.data_dir.clone());
 
-    /// Part number (1 or 2)
-    #[arg(short, long, default_value_t = 1)]
-    part: u32,
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Check { year, timeout } => check(year, timeout),
+        Command::Bench(args) => run_bench(args),
+        Command::Submit {
+            target,
+            part,
+            base_url,
+        } => submit::run(target.year.get(), target.day, part, &base_url),
+        Command::Setup {
+            target,
+            force,
+            offline,
+            fetch_only,
+            base_url,
+            template,
+        } => {
+            let (year, day) = (target.year.get(), target.day);
+            let options = setup::Options {
+                force,
+                offline,
+                fetch_only,
+                base_url,
+                template,
+            };
+            match setup::setup(year, day, &options) {
+                Ok(report) => {
+                    println!("Set up {} day {}:", year, day);
+                    setup::print_report(&report);
+                    println!(
+                        "\n✓ Setup complete! Run with: cargo run -- run --year {} --day {}",
+                        year, day
+                    );
+                }
+                Err(e) => {
+                    eprintln!("Setup failed: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Visualize { target, part, port } => {
+            let year = target.year.supported().unwrap_or_else(|e| e.exit());
+            let port = port.unwrap_or_else(|| config::get().port());
+            visualize(year, target.day, part, port);
+        }
+        Command::Watch { target, part } => {
+            let year = target.year.get();
+            if let Err(e) = registry::lookup_solution(year, target.day, &part.parts()) {
+                e.exit();
+            }
+            watch::watch(year, target.day, part.part);
+        }
+        Command::MigrateInputs { dry_run } => {
+            if let Err(e) = migrate::migrate_inputs(dry_run) {
+                eprintln!("Failed to migrate inputs: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Completions { shell } => {
+            clap_complete::generate(shell, &mut Cli::command(), "aoc", &mut io::stdout());
+        }
+    }
 }
 
-fn main() {
-    let args = Args::parse();
+fn run(args: RunArgs) {
+    let year = args.year.supported().unwrap_or_else(|e| e.exit());
+    let answers_path = answers::answers_path();
+    let timeout = args.timeout.get();
+
+    if args.all {
+        let results = runner::run_all(year, timeout.or(Some(runner::DEFAULT_TIMEOUT)));
+        match args.format {
+            report::Format::Text => runner::print_table(&results),
+            format => report::print(&results, format),
+        }
 
-    if args.day < 1 || args.day > 25 {
-        eprintln!("Invalid day value: {}. Must be between 1 and 25.", args.day);
+        if args.record {
+            answers::record_answers(&answers_path, &results);
+        }
+        exit_on_failure(&results);
+        return;
+    }
+
+    let day = args.day.expect("--day is required unless --all is given");
+    let parts = args.part.parts();
+
+    let solution = registry::lookup_solution(year, day, &parts).unwrap_or_else(|e| e.exit());
+    let input = match (&args.input, args.example) {
+        (Some(path), _) => util::read_input_file(path),
+        (None, Some(example)) => util::get_example(year, day, example),
+        (None, None) => util::get_input(year, day),
+    }
+    .unwrap_or_else(|e| e.exit());
+
+    let results = runner::run_input(year, day, &solution, &input, &parts, timeout);
+    match args.format {
+        report::Format::Text => report::print_results(&results),
+        format => report::print(&results, format),
+    }
+
+    if let Some(example) = args.example {
+        let passed = answers::check_example(year, day, example, &results, args.record);
+        exit_on_failure(&results);
+        if !passed {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if args.record {
+        answers::record_answers(&answers_path, &results);
+    }
+    exit_on_failure(&results);
+}
+
+/// Exits with a non-zero status if any part failed, crashed or hung.
+fn exit_on_failure(results: &[runner::RunResult]) {
+    // Input errors take precedence so scripts can tell them from failing solutions
+    if let Some(error) = results.iter().find_map(|r| match &r.status {
+        Status::Error(error) => Some(error),
+        _ => None,
+    }) {
+        std::process::exit(error.exit_code());
+    }
+    if results.iter().any(|r| r.status.is_failure()) {
         std::process::exit(1);
     }
+}
+
+fn check(year: YearArg, timeout: TimeoutArg) {
+    let year = year.supported().unwrap_or_else(|e| e.exit());
+    let answers_path = answers::answers_path();
+    let store = answers::load_answers(&answers_path);
+    let parts = store.parts(year);
 
-    if args.part != 1 && args.part != 2 {
-        eprintln!("Invalid part value: {}. Must be 1 or 2.", args.part);
+    if parts.is_empty() {
+        eprintln!(
+            "No recorded answers for {} in {}. Record some with `run --record` first.",
+            year,
+            answers_path.display()
+        );
         std::process::exit(1);
     }
 
-    if args.year != 2025 {
-        eprintln!("Invalid year value: {}. Only 2025 is supported.", args.year);
+    let timeout = timeout.get().or(Some(runner::DEFAULT_TIMEOUT));
+    let results = runner::run_parts(year, parts, timeout);
+    if !answers::print_check(&store, &results) {
         std::process::exit(1);
     }
+}
 
-    let start = Instant::now();
+fn run_bench(args: BenchArgs) {
+    let config = config::get();
+    let (year, day) = (args.target.year.get(), args.target.day);
+    let parts = args.part.parts();
 
-    let solution = match args.year {
-        2025 => year2025::get_solution(args.day, args.part),
-        _ => None,
-    };
+    let solution = registry::lookup_solution(year, day, &parts).unwrap_or_else(|e| e.exit());
+    let input = match &args.input {
+        Some(path) => util::read_input_file(path),
+        None => util::get_input(year, day),
+    }
+    .unwrap_or_else(|e| e.exit());
 
-    match solution {
-        Some(func) => func(),
-        None => {
-            eprintln!(
-                "Solution not implemented for year {}, day {}, part {}",
-                args.year, args.day, args.part
-            );
-            std::process::exit(1);
-        }
+    let options = bench::Options {
+        warmup: args.warmup.unwrap_or(config.warmup()),
+        iterations: args.iterations.unwrap_or(config.iterations()),
+        compare: args.compare,
+        baseline: args.baseline,
+        threshold: args.threshold.unwrap_or(config.threshold()),
+    };
+    let passed =
+        bench::run(year, day, &solution, &input, &parts, &options).unwrap_or_else(|e| e.exit());
+    if !passed {
+        std::process::exit(1);
     }
+}
 
-    let duration = start.elapsed();
-    println!("\nExecution Time: {:?}", duration);
+fn visualize(year: u32, day: u32, part: u32, port: u16) {
+    let Some(visualizer) = registry::get_visualizer(year, day, part) else {
+        eprintln!(
+            "No visualizer available for year {} day {} part {}",
+            year, day, part
+        );
+        std::process::exit(1);
+    };
+
+    let runtime = tokio::runtime::Runtime::new().unwrap_or_else(|e| {
+        eprintln!("Failed to start the async runtime: {}", e);
+        std::process::exit(1);
+    });
+    runtime.block_on(visualizer(port));
 }
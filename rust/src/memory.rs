@@ -0,0 +1,117 @@
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "alloc-stats")]
+use std::alloc::{GlobalAlloc, Layout, System};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static BASELINE: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Heap usage over a measurement window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Highest heap usage above the level at the start of the window
+    pub peak_bytes: usize,
+    /// Number of allocations and reallocations
+    pub allocations: usize,
+}
+
+impl AllocStats {
+    /// Combines consecutive windows: the highest peak and the total count.
+    pub fn merge(self, other: AllocStats) -> AllocStats {
+        AllocStats {
+            peak_bytes: self.peak_bytes.max(other.peak_bytes),
+            allocations: self.allocations + other.allocations,
+        }
+    }
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+        let mut size = self.peak_bytes as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        if unit == 0 {
+            write!(f, "peak heap {} B", self.peak_bytes)?;
+        } else {
+            write!(f, "peak heap {:.1} {}", size, UNITS[unit])?;
+        }
+        write!(f, ", {} allocations", self.allocations)
+    }
+}
+
+/// Global allocator that forwards to the system allocator while counting
+/// allocations and tracking peak heap usage. Installed by the `aoc` binary
+/// when built with the `alloc-stats` feature.
+#[cfg(feature = "alloc-stats")]
+pub struct CountingAllocator;
+
+#[cfg(feature = "alloc-stats")]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            grow(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+fn grow(size: usize) {
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Starts a new measurement window at the current heap usage.
+pub fn reset() {
+    let current = CURRENT.load(Ordering::Relaxed);
+    BASELINE.store(current, Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+}
+
+/// Heap usage since the last `reset`, or `None` if the counting allocator is not installed.
+pub fn stats() -> Option<AllocStats> {
+    if !cfg!(feature = "alloc-stats") {
+        return None;
+    }
+
+    Some(AllocStats {
+        peak_bytes: PEAK
+            .load(Ordering::Relaxed)
+            .saturating_sub(BASELINE.load(Ordering::Relaxed)),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+    })
+}
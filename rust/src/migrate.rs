@@ -0,0 +1,87 @@
+use crate::paths::{input_root, source_dir};
+use crate::util::{input_path, legacy_input_path};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Moves every `src/<year>/<day>/input.txt` into the input cache (or the
+/// configured data directory). Inputs that are already there with the same
+/// content are removed from the source tree; conflicting ones are left alone.
+pub fn migrate_inputs(dry_run: bool) -> io::Result<()> {
+    let inputs = legacy_inputs()?;
+    if inputs.is_empty() {
+        println!("No inputs left in {:?}, nothing to migrate.", source_dir());
+        return Ok(());
+    }
+
+    println!("Moving inputs to {:?}", input_root());
+    let mut moved = 0;
+    for (year, day) in &inputs {
+        let (year, day) = (*year, *day);
+        let from = legacy_input_path(year, day);
+        let to = input_path(year, day);
+
+        if to.exists() {
+            if fs::read(&from)? != fs::read(&to)? {
+                println!(
+                    "  {} day {:>2}: skipped, a different input is already at {:?}",
+                    year, day, to
+                );
+                continue;
+            }
+            if !dry_run {
+                fs::remove_file(&from)?;
+            }
+            println!(
+                "  {} day {:>2}: already migrated, removed the copy in src/",
+                year, day
+            );
+            moved += 1;
+            continue;
+        }
+
+        if !dry_run {
+            move_file(&from, &to)?;
+        }
+        println!("  {} day {:>2}: moved", year, day);
+        moved += 1;
+    }
+
+    let verb = if dry_run { "Would migrate" } else { "Migrated" };
+    println!("{} {}/{} inputs", verb, moved, inputs.len());
+    Ok(())
+}
+
+/// `(year, day)` of every input still stored next to its solution module.
+fn legacy_inputs() -> io::Result<Vec<(u32, u32)>> {
+    let mut inputs = Vec::new();
+    for year in numbered_dirs(&source_dir())? {
+        for day in numbered_dirs(&source_dir().join(year.to_string()))? {
+            if legacy_input_path(year, day).is_file() {
+                inputs.push((year, day));
+            }
+        }
+    }
+    inputs.sort_unstable();
+    Ok(inputs)
+}
+
+fn numbered_dirs(dir: &Path) -> io::Result<Vec<u32>> {
+    Ok(fs::read_dir(dir)?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+        .collect())
+}
+
+/// Renames `from` to `to`, copying instead when they are on different filesystems.
+fn move_file(from: &Path, to: &Path) -> io::Result<()> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if fs::rename(from, to).is_err() {
+        fs::copy(from, to)?;
+        fs::remove_file(from)?;
+    }
+    Ok(())
+}
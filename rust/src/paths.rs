@@ -0,0 +1,98 @@
+use crate::config;
+use crate::util::input_hash;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Environment variable overriding the data directory
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+
+/// Environment variable holding the adventofcode.com session cookie
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// The explicitly configured data directory, if any
+static DATA_DIR: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+/// Resolves the data directory once per process, from the first of: the
+/// `--data-dir` flag, `AOC_DATA_DIR` and `data_dir` in `aoc.toml`. Binaries
+/// call this before touching any input; later calls return the already
+/// resolved directory.
+pub fn init_data_dir(flag: Option<PathBuf>) -> Option<&'static Path> {
+    DATA_DIR
+        .get_or_init(|| {
+            flag.or_else(|| env::var_os(DATA_DIR_ENV).map(PathBuf::from))
+                .or_else(|| config::get().data_dir.clone())
+        })
+        .as_deref()
+}
+
+/// The explicitly configured data directory, or `None` when inputs live in
+/// the per-user cache.
+pub fn configured_data_dir() -> Option<&'static Path> {
+    init_data_dir(None)
+}
+
+/// The directory holding `<year>/<day>/` with the example inputs: the
+/// configured data directory, or the crate's `src` directory.
+pub fn data_dir() -> PathBuf {
+    configured_data_dir()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(source_dir)
+}
+
+/// The directory holding `<year>/<day>/input.txt`: the configured data
+/// directory, or the per-user input cache for the current session account.
+pub fn input_root() -> PathBuf {
+    match configured_data_dir() {
+        Some(dir) => dir.to_path_buf(),
+        None => cache_dir().join(account()),
+    }
+}
+
+/// Per-user cache of puzzle inputs, outside the source tree.
+pub fn cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(env::temp_dir)
+        .join("advent-of-code")
+}
+
+/// Identifies the account owning the inputs without storing the session
+/// cookie itself: `account` in `aoc.toml`, else a short hash of the session,
+/// or "anonymous" without one.
+pub fn account() -> String {
+    if let Some(account) = &config::get().account {
+        return account.clone();
+    }
+    match session() {
+        Some(session) => input_hash(&session)[..12].to_string(),
+        None => "anonymous".to_string(),
+    }
+}
+
+/// The adventofcode.com session cookie, from `AOC_SESSION` or else the
+/// `session_file` named in `aoc.toml`.
+pub fn session() -> Option<String> {
+    let session = match env::var(SESSION_ENV) {
+        Ok(session) => session,
+        Err(_) => fs::read_to_string(config::get().session_file.as_ref()?).ok()?,
+    };
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+/// The crate directory, which holds the files `aoc` keeps next to the
+/// sources no matter where it is run from.
+pub fn crate_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Directory holding the `mod.rs` templates used by `setup`.
+pub fn templates_dir() -> PathBuf {
+    crate_dir().join("templates")
+}
+
+/// The crate's `src` directory, where the solution modules live.
+pub fn source_dir() -> PathBuf {
+    crate_dir().join("src")
+}
@@ -0,0 +1,296 @@
+use crate::html::{articles, decode_entities, strip_tags};
+use crate::http::USER_AGENT;
+
+/// Downloads a day's puzzle page. Without a session only part 1 is included.
+pub fn fetch_page(
+    base_url: &str,
+    session: Option<&str>,
+    year: u32,
+    day: u32,
+) -> reqwest::Result<String> {
+    let url = format!("{}/{}/day/{}", base_url.trim_end_matches('/'), year, day);
+
+    let mut request = reqwest::blocking::Client::new()
+        .get(&url)
+        .header("User-Agent", USER_AGENT);
+    if let Some(session) = session {
+        request = request.header("Cookie", format!("session={}", session));
+    }
+    request.send()?.error_for_status()?.text()
+}
+
+/// Converts the puzzle descriptions (one `<article>` per unlocked part) of a
+/// puzzle page to Markdown. Links to the site are made absolute.
+pub fn to_markdown(html: &str, base_url: &str) -> String {
+    let mut markdown = Markdown {
+        out: String::new(),
+        base_url: base_url.trim_end_matches('/'),
+        links: Vec::new(),
+        heading: None,
+        in_pre: false,
+        in_code: false,
+    };
+
+    for article in articles(html) {
+        markdown.convert(article);
+    }
+
+    let mut out = markdown.out.trim_end().to_string();
+    out.push('\n');
+    out
+}
+
+/// The puzzle's title, from the first heading of its Markdown description
+/// ("## Day 1: Secret Entrance").
+pub fn title(markdown: &str) -> Option<String> {
+    let heading = markdown.lines().find_map(|line| line.strip_prefix("## "))?;
+    let title = heading.split_once(": ").map_or(heading, |(_, title)| title);
+    Some(title.trim().to_string())
+}
+
+/// The first `<pre><code>` block of the puzzle descriptions, which is the
+/// example input of nearly every puzzle.
+pub fn first_code_block(html: &str) -> Option<String> {
+    articles(html).find_map(|article| {
+        let start = article.find("<pre><code>")? + "<pre><code>".len();
+        let end = article[start..].find("</code></pre>")? + start;
+        Some(decode_entities(&strip_tags(&article[start..end])))
+    })
+}
+
+/// Writes Markdown for the handful of tags used in puzzle descriptions:
+/// headings, paragraphs, lists, links, emphasis, inline code and code blocks.
+struct Markdown<'a> {
+    out: String,
+    base_url: &'a str,
+    /// Targets of the links currently open
+    links: Vec<String>,
+    /// Where the text of the heading currently open starts
+    heading: Option<usize>,
+    in_pre: bool,
+    in_code: bool,
+}
+
+impl Markdown<'_> {
+    fn convert(&mut self, html: &str) {
+        let mut rest = html;
+        while let Some(start) = rest.find('<') {
+            self.text(&rest[..start]);
+            let Some(end) = rest[start..].find('>') else {
+                rest = &rest[start..];
+                break;
+            };
+            self.tag(&rest[start + 1..start + end]);
+            rest = &rest[start + end + 1..];
+        }
+        self.text(rest);
+        self.end_block();
+    }
+
+    fn tag(&mut self, tag: &str) {
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("h2", false) => {
+                self.end_block();
+                self.out.push_str("## ");
+                self.heading = Some(self.out.len());
+            }
+            ("h2", true) => {
+                if let Some(start) = self.heading.take() {
+                    // "--- Day 1: Title ---" reads better without the dashes
+                    let title = self.out[start..]
+                        .trim()
+                        .trim_matches('-')
+                        .trim()
+                        .to_string();
+                    self.out.truncate(start);
+                    self.out.push_str(&title);
+                }
+                self.end_block();
+            }
+            ("p" | "ul", _) => self.end_block(),
+            ("li", false) => {
+                self.end_line();
+                self.out.push_str("- ");
+            }
+            ("li", true) => self.end_line(),
+            ("pre", false) => {
+                self.end_block();
+                self.out.push_str("```\n");
+                self.in_pre = true;
+            }
+            ("pre", true) => {
+                self.end_line();
+                self.out.push_str("```");
+                self.in_pre = false;
+                self.end_block();
+            }
+            ("code", _) if !self.in_pre => {
+                self.out.push('`');
+                self.in_code = !closing;
+            }
+            ("em", _) if !self.in_pre && !self.in_code => self.out.push('*'),
+            ("a", false) => {
+                self.out.push('[');
+                self.links.push(self.link_target(tag));
+            }
+            ("a", true) => {
+                let target = self.links.pop().unwrap_or_default();
+                self.out.push_str(&format!("]({})", target));
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        let text = decode_entities(text);
+        if self.in_pre {
+            self.out.push_str(&text);
+            return;
+        }
+
+        // Outside code blocks, any run of whitespace is a single space
+        for (i, word) in text.split_whitespace().enumerate() {
+            if i > 0 || text.starts_with(char::is_whitespace) {
+                self.space();
+            }
+            self.out.push_str(word);
+        }
+        if text.ends_with(char::is_whitespace) {
+            self.space();
+        }
+    }
+
+    fn space(&mut self) {
+        if !self.out.is_empty() && !self.out.ends_with([' ', '\n']) {
+            self.out.push(' ');
+        }
+    }
+
+    fn end_line(&mut self) {
+        self.trim_spaces();
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    fn end_block(&mut self) {
+        let len = self.out.trim_end().len();
+        self.out.truncate(len);
+        if !self.out.is_empty() {
+            self.out.push_str("\n\n");
+        }
+    }
+
+    fn trim_spaces(&mut self) {
+        let len = self.out.trim_end_matches(' ').len();
+        self.out.truncate(len);
+    }
+
+    fn link_target(&self, tag: &str) -> String {
+        let href = tag
+            .split_once("href=\"")
+            .and_then(|(_, rest)| rest.split_once('"'))
+            .map(|(href, _)| decode_entities(href))
+            .unwrap_or_default();
+
+        if href.starts_with('/') {
+            format!("{}{}", self.base_url, href)
+        } else {
+            href
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A day's page with both parts unlocked, trimmed to a few paragraphs
+    const PAGE: &str = include_str!("../tests/fixtures/puzzle.html");
+
+    const BASE_URL: &str = "https://adventofcode.com/";
+
+    #[test]
+    fn converts_both_articles() {
+        let markdown = to_markdown(PAGE, BASE_URL);
+
+        assert!(markdown.starts_with("## Day 1: Secret Entrance\n\nThe Elves"));
+        assert!(markdown.contains("\n\n## Part Two\n\n"));
+        assert!(markdown.ends_with("*What is the new password?*\n"));
+        // Only the articles are converted, not the answers and form around them
+        assert!(!markdown.contains("Your puzzle answer"));
+        assert!(!markdown.contains("Advent of Code"));
+    }
+
+    #[test]
+    fn decodes_entities() {
+        let markdown = to_markdown(PAGE, BASE_URL);
+
+        assert!(markdown.contains("good news & bad news"));
+        assert!(markdown.contains("`<direction><distance>`"));
+    }
+
+    #[test]
+    fn makes_site_links_absolute() {
+        let markdown = to_markdown(PAGE, BASE_URL);
+
+        assert!(markdown.contains("[project management](https://adventofcode.com/2025/about)"));
+        assert!(markdown
+            .contains("\"method [0x434C49434B](https://en.wikipedia.org/wiki/Hexadecimal)\""));
+    }
+
+    #[test]
+    fn converts_inline_markup() {
+        let markdown = to_markdown(PAGE, BASE_URL);
+
+        assert!(markdown.contains("*any click*"));
+        // Emphasis inside inline code would show up literally, so it is dropped
+        assert!(markdown.contains("the password in this example is `3`."));
+        assert!(markdown.contains(
+            "- The dial starts by pointing at `50`.\n- The dial is rotated `L68` to point at `82`.\n"
+        ));
+    }
+
+    #[test]
+    fn keeps_code_blocks_verbatim() {
+        let markdown = to_markdown(PAGE, BASE_URL);
+
+        assert!(markdown.contains("take these rotations:\n\n```\nL68\nL30\nR48\nL5\n```\n\n"));
+        assert!(markdown.contains("```\nL68\nR1000\n```"));
+    }
+
+    #[test]
+    fn finds_first_code_block() {
+        assert_eq!(
+            first_code_block(PAGE).as_deref(),
+            Some("L68\nL30\nR48\nL5\n")
+        );
+        assert_eq!(
+            first_code_block("<article><pre><code>a &lt; <em>b</em>\n</code></pre></article>")
+                .as_deref(),
+            Some("a < b\n")
+        );
+        assert_eq!(
+            first_code_block("<article><p>No example</p></article>"),
+            None
+        );
+    }
+
+    #[test]
+    fn reads_title() {
+        assert_eq!(
+            title(&to_markdown(PAGE, BASE_URL)).as_deref(),
+            Some("Secret Entrance")
+        );
+        assert_eq!(title("## Part Two\n").as_deref(), Some("Part Two"));
+        assert_eq!(title("No heading\n"), None);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::error::{Error, Result};
+use crate::solution::{downcast, Solution};
+use std::future::Future;
+use std::pin::Pin;
+
+/// Starts the visualization server for a puzzle part on the given port.
+pub type Visualizer = fn(u16) -> Pin<Box<dyn Future<Output = ()>>>;
+
+// `YEARS`, `get_solution` and `get_visualizer` are generated by build.rs from
+// the `src/<year>/<day>/mod.rs` directories.
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+pub fn is_supported(year: u32) -> bool {
+    YEARS.contains(&year)
+}
+
+/// Validates the year, day and parts and returns the day's solution.
+pub fn lookup_solution(year: u32, day: u32, parts: &[u32]) -> Result<Solution> {
+    if !is_supported(year) {
+        return Err(Error::UnsupportedYear(year));
+    }
+
+    if let Some(&part) = parts.iter().find(|&&part| part != 1 && part != 2) {
+        return Err(Error::UnsupportedPart(part));
+    }
+
+    get_solution(year, day).ok_or(Error::UnsupportedDay { year, day })
+}
@@ -0,0 +1,194 @@
+use crate::memory::AllocStats;
+use crate::runner::{RunResult, Status};
+use crate::solution::Answer;
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// How run results are written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable output
+    Text,
+    /// A JSON array with one object per part
+    Json,
+    /// A header row followed by one row per part
+    Csv,
+}
+
+/// One result in machine-readable form.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: Option<String>,
+    /// Time spent parsing the input, in nanoseconds
+    pub parse_time_ns: Option<u128>,
+    /// Time spent solving the part, in nanoseconds
+    pub time_ns: Option<u128>,
+    /// SHA-256 of the input, as lowercase hex
+    pub input_hash: Option<String>,
+    pub status: &'static str,
+    /// Panic message, time limit or error for failed and skipped parts
+    pub error: Option<String>,
+}
+
+impl From<&RunResult> for Record {
+    fn from(result: &RunResult) -> Self {
+        let error = match &result.status {
+            Status::Panicked(message) => Some(message.clone()),
+            Status::TimedOut(limit) => Some(format!("timed out after {:?}", limit)),
+            Status::Error(error) | Status::NoInput(error) => Some(error.to_string()),
+            _ => None,
+        };
+
+        Record {
+            year: result.year,
+            day: result.day,
+            part: result.part,
+            answer: result.answer.as_ref().map(|answer| answer.to_string()),
+            parse_time_ns: result.parse_time.map(|t| t.as_nanos()),
+            time_ns: result.duration.map(|t| t.as_nanos()),
+            input_hash: result.input_hash.clone(),
+            status: result.status.label(),
+            error,
+        }
+    }
+}
+
+const CSV_HEADER: [&str; 9] = [
+    "year",
+    "day",
+    "part",
+    "answer",
+    "parse_time_ns",
+    "time_ns",
+    "input_hash",
+    "status",
+    "error",
+];
+
+/// Prints results as JSON or CSV. `Format::Text` is handled by the callers,
+/// since single-day runs and `--all` present results differently.
+pub fn print(results: &[RunResult], format: Format) {
+    let records: Vec<Record> = results.iter().map(Record::from).collect();
+
+    match format {
+        Format::Text => {}
+        Format::Json => match serde_json::to_string_pretty(&records) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("Failed to serialize results: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Format::Csv => {
+            println!("{}", CSV_HEADER.join(","));
+            for record in &records {
+                println!("{}", csv_row(record));
+            }
+        }
+    }
+}
+
+fn csv_row(record: &Record) -> String {
+    let optional = |value: Option<String>| value.unwrap_or_default();
+    let fields = [
+        record.year.to_string(),
+        record.day.to_string(),
+        record.part.to_string(),
+        optional(record.answer.clone()),
+        optional(record.parse_time_ns.map(|t| t.to_string())),
+        optional(record.time_ns.map(|t| t.to_string())),
+        optional(record.input_hash.clone()),
+        record.status.to_string(),
+        optional(record.error.clone()),
+    ];
+
+    fields
+        .iter()
+        .map(|field| csv_escape(field))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Quotes a field if it contains a separator, quote or line break (RFC 4180).
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Prints answers followed by the parse, per-part and total times.
+pub fn print_results(results: &[RunResult]) {
+    for result in results {
+        match (&result.status, &result.answer) {
+            (Status::Ok, Some(answer)) => print_answer(result.part, answer),
+            (Status::Panicked(message), _) => {
+                eprintln!("Part {} panicked: {}", result.part, message)
+            }
+            (Status::TimedOut(limit), _) => {
+                eprintln!("Part {} timed out after {:?}", result.part, limit)
+            }
+            (Status::Error(error), _) => {
+                eprintln!("Part {} failed: {}", result.part, error);
+                if let Some(hint) = error.hint() {
+                    eprintln!("  hint: {}", hint);
+                }
+            }
+            _ => println!("Part {} not implemented yet.", result.part),
+        }
+    }
+
+    let parse = results
+        .iter()
+        .find(|r| r.parse_time.is_some())
+        .map(|r| (r.parse_time.unwrap_or_default(), r.parse_alloc));
+    // Without a solved part there is no execution time worth reporting
+    let solved = results.iter().any(|r| r.duration.is_some());
+    match parse {
+        Some((parse_time, alloc)) => {
+            println!("\nParse Time: {:?}{}", parse_time, format_alloc(alloc))
+        }
+        None if solved => println!(),
+        None => {}
+    }
+
+    let mut total = parse.map(|(t, _)| t).unwrap_or_default();
+    let mut total_alloc = parse.and_then(|(_, a)| a);
+    for result in results {
+        if let Some(duration) = result.duration {
+            println!(
+                "Part {} Time: {:?}{}",
+                result.part,
+                duration,
+                format_alloc(result.alloc)
+            );
+            total += duration;
+            total_alloc = match (total_alloc, result.alloc) {
+                (Some(a), Some(b)) => Some(a.merge(b)),
+                (a, b) => a.or(b),
+            };
+        }
+    }
+    if solved {
+        println!("Execution Time: {:?}{}", total, format_alloc(total_alloc));
+    }
+}
+
+/// Formats heap usage as a suffix for a timing line, if it was measured.
+fn format_alloc(alloc: Option<AllocStats>) -> String {
+    alloc
+        .map(|alloc| format!(" ({})", alloc))
+        .unwrap_or_default()
+}
+
+pub fn print_answer(part: u32, answer: &Answer) {
+    match answer {
+        Answer::Unimplemented => println!("Part {} not implemented yet.", part),
+        answer if answer.is_multiline() => println!("Part {}:\n{}", part, answer),
+        answer => println!("Part {}: {}", part, answer),
+    }
+}
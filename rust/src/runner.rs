@@ -0,0 +1,376 @@
+use crate::error::{self, Error};
+use crate::memory::{self, AllocStats};
+use crate::registry::get_solution;
+use crate::solution::{Answer, Solution};
+use crate::util::{get_input, input_hash};
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Per-part time limit for `run --all` and `check` when `--timeout` is not given
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Stack size of the worker threads, generous since recursive solutions used
+/// to run on the main thread
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Outcome of running a single puzzle part.
+#[derive(Debug, Clone)]
+pub enum Status {
+    Ok,
+    NotImplemented,
+    /// The day's input is missing or still the placeholder of an offline
+    /// `setup`, so the part was skipped
+    NoInput(Error),
+    /// The input could not be loaded or parsed, or the solution reported an error
+    Error(Error),
+    Panicked(String),
+    /// Still running when the time limit was hit
+    TimedOut(Duration),
+}
+
+impl Status {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::NotImplemented => "not implemented",
+            Status::NoInput(_) => "no input",
+            Status::Error(_) => "error",
+            Status::Panicked(_) => "panicked",
+            Status::TimedOut(_) => "timed out",
+        }
+    }
+
+    /// Whether the part failed, crashed or hung, as opposed to succeeding or being unsolved
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Status::Error(_) | Status::Panicked(_) | Status::TimedOut(_)
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RunResult {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: Option<Answer>,
+    /// Time spent parsing the input, shared by both parts of a day
+    pub parse_time: Option<Duration>,
+    /// Time spent solving the part, excluding parsing
+    pub duration: Option<Duration>,
+    /// Heap usage while parsing, when built with the `alloc-stats` feature
+    pub parse_alloc: Option<AllocStats>,
+    /// Heap usage while solving, when built with the `alloc-stats` feature
+    pub alloc: Option<AllocStats>,
+    /// SHA-256 of the input the part ran on, if it could be read
+    pub input_hash: Option<String>,
+    pub status: Status,
+}
+
+impl RunResult {
+    fn failed(year: u32, day: u32, part: u32, status: Status) -> Self {
+        RunResult {
+            year,
+            day,
+            part,
+            answer: None,
+            parse_time: None,
+            duration: None,
+            parse_alloc: None,
+            alloc: None,
+            input_hash: None,
+            status,
+        }
+    }
+}
+
+/// Parses `input` once and solves each of `parts` on the shared parsed value.
+/// The parser and every part run on a worker thread: panics are turned into
+/// `Status::Panicked` without the default panic output, and anything still
+/// running after `timeout` is reported as `Status::TimedOut`.
+pub fn run_input(
+    year: u32,
+    day: u32,
+    solution: &Solution,
+    input: &str,
+    parts: &[u32],
+    timeout: Option<Duration>,
+) -> Vec<RunResult> {
+    let hash = input_hash(input);
+    let mut results = silenced(|| solve_input(year, day, solution, input, parts, timeout));
+    for result in &mut results {
+        result.input_hash = Some(hash.clone());
+    }
+    results
+}
+
+fn solve_input(
+    year: u32,
+    day: u32,
+    solution: &Solution,
+    input: &str,
+    parts: &[u32],
+    timeout: Option<Duration>,
+) -> Vec<RunResult> {
+    let solution = *solution;
+    let input: Arc<str> = Arc::from(input);
+
+    let parsed = isolated(
+        format!("day {} parse", day),
+        move || (solution.parse)(&input).map(Arc::new),
+        timeout,
+    );
+    let (parsed, parse_time, parse_alloc) = match parsed {
+        Ok(parsed) => parsed,
+        Err(status) => {
+            return parts
+                .iter()
+                .map(|&part| RunResult::failed(year, day, part, status.clone()))
+                .collect();
+        }
+    };
+
+    parts
+        .iter()
+        .map(|&part| {
+            let parsed = Arc::clone(&parsed);
+            let name = format!("day {} part {}", day, part);
+            match isolated(name, move || solution.solve(part, &parsed), timeout) {
+                Ok((Answer::Unimplemented, _, _)) => {
+                    RunResult::failed(year, day, part, Status::NotImplemented)
+                }
+                Ok((answer, duration, alloc)) => RunResult {
+                    year,
+                    day,
+                    part,
+                    answer: Some(answer),
+                    parse_time: Some(parse_time),
+                    duration: Some(duration),
+                    parse_alloc,
+                    alloc,
+                    input_hash: None,
+                    status: Status::Ok,
+                },
+                Err(status) => RunResult::failed(year, day, part, status),
+            }
+        })
+        .collect()
+}
+
+/// Runs `f` on a worker thread and waits at most `timeout` for it to finish.
+/// A thread that times out cannot be stopped, so it is left running in the
+/// background until the process exits.
+fn isolated<T: Send + 'static>(
+    name: String,
+    f: impl FnOnce() -> error::Result<T> + Send + 'static,
+    timeout: Option<Duration>,
+) -> Result<(T, Duration, Option<AllocStats>), Status> {
+    let (tx, rx) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .name(name)
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let outcome = panic::catch_unwind(AssertUnwindSafe(|| measured(f)));
+            // The receiver is gone if the run already timed out
+            let _ = tx.send(outcome);
+        });
+    if let Err(e) = spawned {
+        return Err(Status::Panicked(format!(
+            "failed to spawn worker thread: {}",
+            e
+        )));
+    }
+
+    let received = match timeout {
+        Some(timeout) => rx.recv_timeout(timeout),
+        None => rx.recv().map_err(mpsc::RecvTimeoutError::from),
+    };
+
+    match received {
+        Ok(Ok((Ok(value), duration, alloc))) => Ok((value, duration, alloc)),
+        Ok(Ok((Err(error), _, _))) => Err(Status::Error(error)),
+        Ok(Err(payload)) => Err(Status::Panicked(panic_message(payload))),
+        Err(mpsc::RecvTimeoutError::Timeout) => Err(Status::TimedOut(timeout.unwrap_or_default())),
+        Err(mpsc::RecvTimeoutError::Disconnected) => Err(Status::Panicked(
+            "worker thread exited without a result".to_string(),
+        )),
+    }
+}
+
+/// Loads the input for a day and runs the requested parts on it.
+pub fn run_day(year: u32, day: u32, parts: &[u32], timeout: Option<Duration>) -> Vec<RunResult> {
+    let Some(solution) = get_solution(year, day) else {
+        return parts
+            .iter()
+            .map(|&part| RunResult::failed(year, day, part, Status::NotImplemented))
+            .collect();
+    };
+
+    match get_input(year, day) {
+        Ok(input) => run_input(year, day, &solution, &input, parts, timeout),
+        Err(error) => parts
+            .iter()
+            .map(|&part| RunResult::failed(year, day, part, Status::Error(error.clone())))
+            .collect(),
+    }
+}
+
+/// Runs both parts of every registered day in `year`. Days without an input
+/// are reported as [`Status::NoInput`] rather than as errors, since scaffolded
+/// days usually have none yet.
+pub fn run_all(year: u32, timeout: Option<Duration>) -> Vec<RunResult> {
+    let parts = (1..=25)
+        .filter(|&day| get_solution(year, day).is_some())
+        .flat_map(|day| [(day, 1), (day, 2)]);
+
+    run_parts(year, parts, timeout)
+        .into_iter()
+        .map(|mut result| {
+            if let Status::Error(error @ Error::MissingInput { .. }) = &result.status {
+                result.status = Status::NoInput(error.clone());
+            }
+            result
+        })
+        .collect()
+}
+
+/// Runs the given `(day, part)` pairs, parsing each day once.
+pub fn run_parts(
+    year: u32,
+    parts: impl IntoIterator<Item = (u32, u32)>,
+    timeout: Option<Duration>,
+) -> Vec<RunResult> {
+    let mut days: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    for (day, part) in parts {
+        days.entry(day).or_default().push(part);
+    }
+
+    days.iter()
+        .flat_map(|(&day, parts)| run_day(year, day, parts, timeout))
+        .collect()
+}
+
+/// Runs `f` with the default panic output silenced, so that panics caught on
+/// worker threads are only reported once, as `Status::Panicked`.
+fn silenced<T>(f: impl FnOnce() -> T) -> T {
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = f();
+    panic::set_hook(previous_hook);
+    result
+}
+
+/// Runs `f`, returning its result, how long it took and, when the counting
+/// allocator is installed, how much heap it used.
+pub fn measured<T>(f: impl FnOnce() -> T) -> (T, Duration, Option<AllocStats>) {
+    memory::reset();
+    let start = Instant::now();
+    let result = f();
+    let duration = start.elapsed();
+    (result, duration, memory::stats())
+}
+
+pub fn print_table(results: &[RunResult]) {
+    let answers: Vec<String> = results
+        .iter()
+        .map(|r| match &r.answer {
+            Some(answer) if answer.is_multiline() => "(multiline)".to_string(),
+            Some(answer) => answer.to_string(),
+            None => String::new(),
+        })
+        .collect();
+    let format_time = |d: Option<Duration>| d.map(|d| format!("{:?}", d)).unwrap_or_default();
+    let parse_times: Vec<String> = results.iter().map(|r| format_time(r.parse_time)).collect();
+    let times: Vec<String> = results.iter().map(|r| format_time(r.duration)).collect();
+
+    let answer_width = answers.iter().map(|a| a.len()).max().unwrap_or(0).max(6);
+    let parse_width = parse_times
+        .iter()
+        .map(|t| t.len())
+        .max()
+        .unwrap_or(0)
+        .max(5);
+    let time_width = times.iter().map(|t| t.len()).max().unwrap_or(0).max(4);
+
+    println!(
+        "{:>3}  {:>4}  {:<aw$}  {:>pw$}  {:>tw$}  Status",
+        "Day",
+        "Part",
+        "Answer",
+        "Parse",
+        "Time",
+        aw = answer_width,
+        pw = parse_width,
+        tw = time_width
+    );
+    println!(
+        "{}  {}  {}  {}  {}  {}",
+        "-".repeat(3),
+        "-".repeat(4),
+        "-".repeat(answer_width),
+        "-".repeat(parse_width),
+        "-".repeat(time_width),
+        "-".repeat(15)
+    );
+
+    for (i, result) in results.iter().enumerate() {
+        println!(
+            "{:>3}  {:>4}  {:<aw$}  {:>pw$}  {:>tw$}  {}",
+            result.day,
+            result.part,
+            answers[i],
+            parse_times[i],
+            times[i],
+            result.status.label(),
+            aw = answer_width,
+            pw = parse_width,
+            tw = time_width
+        );
+    }
+
+    let solved = results
+        .iter()
+        .filter(|r| matches!(r.status, Status::Ok))
+        .count();
+
+    // Each day is parsed once, so count its parse time once
+    let parse_total: Duration = results
+        .iter()
+        .filter_map(|r| r.parse_time.map(|t| ((r.year, r.day), t)))
+        .collect::<BTreeMap<_, _>>()
+        .values()
+        .sum();
+    let total = parse_total + results.iter().filter_map(|r| r.duration).sum::<Duration>();
+    println!("\n{}/{} parts solved in {:?}", solved, results.len(), total);
+
+    for result in results {
+        match &result.status {
+            Status::Panicked(message) => eprintln!(
+                "Day {} part {} panicked: {}",
+                result.day, result.part, message
+            ),
+            Status::TimedOut(limit) => eprintln!(
+                "Day {} part {} timed out after {:?}",
+                result.day, result.part, limit
+            ),
+            Status::Error(error) => {
+                eprintln!("Day {} part {} failed: {}", result.day, result.part, error)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
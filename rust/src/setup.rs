@@ -0,0 +1,278 @@
+use crate::http::USER_AGENT;
+use crate::util::{day_dir, example_path, input_path, legacy_input_path, puzzle_path};
+use crate::{paths, puzzle};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Template used when `--template` is not given
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+/// What `setup` did with one file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Created,
+    /// An empty input was created because the real one could not be fetched
+    Placeholder,
+    Updated,
+    /// Left alone, with the reason
+    Skipped(&'static str),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Outcome::Created | Outcome::Placeholder => "created",
+            Outcome::Updated => "updated",
+            Outcome::Skipped(_) => "skipped",
+        })
+    }
+}
+
+/// How `setup` treats existing files and the network.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Overwrite an existing `mod.rs` and re-download an existing input
+    pub force: bool,
+    /// Do not download anything, leave an empty input placeholder instead
+    pub offline: bool,
+    /// Only download the input and puzzle description, replacing an input
+    /// placeholder, and fail if the input cannot be downloaded
+    pub fetch_only: bool,
+    /// Server to download from
+    pub base_url: String,
+    /// Name of the template in `templates/` to create `mod.rs` from
+    pub template: String,
+}
+
+/// Downloads a day's input into the input cache (or the configured data
+/// directory), saves the puzzle description and example input next to the
+/// other examples and scaffolds its solution module next to the other days.
+/// Existing files are only replaced with `force`, so running it again for a
+/// solved day just fetches whatever is missing; the description is always
+/// refreshed, to pick up part 2 once it unlocks. When the input cannot be
+/// fetched an empty placeholder is written instead, to be replaced later with
+/// `fetch_only`. Returns what happened to each file.
+pub fn setup(
+    year: u32,
+    day: u32,
+    options: &Options,
+) -> Result<Vec<(PathBuf, Outcome)>, Box<dyn Error>> {
+    let mut report = Vec::new();
+
+    // Load the template first, so a misspelt name fails before any download
+    let template = (!options.fetch_only)
+        .then(|| load_template(&options.template))
+        .transpose()?;
+
+    // Inputs still kept next to the solution count as present
+    let legacy = legacy_input_path(year, day);
+    let input_path = if legacy.is_file() && !input_path(year, day).exists() {
+        legacy
+    } else {
+        input_path(year, day)
+    };
+    let outcome = setup_input(year, day, &input_path, options)?;
+    report.push((input_path, outcome));
+    if !options.offline {
+        setup_puzzle(year, day, options, &mut report)?;
+    }
+    let Some(template) = template else {
+        return Ok(report);
+    };
+
+    // The day is registered automatically by build.rs on the next build
+    let title = fs::read_to_string(puzzle_path(year, day))
+        .ok()
+        .and_then(|markdown| puzzle::title(&markdown))
+        .unwrap_or_else(|| "untitled".to_string());
+    let mod_path = day_dir(year, day).join("mod.rs");
+    let outcome = write_file(&mod_path, options.force, || {
+        Ok(render_template(&template, year, day, &title))
+    })?;
+    report.push((mod_path, outcome));
+
+    Ok(report)
+}
+
+fn setup_input(
+    year: u32,
+    day: u32,
+    path: &Path,
+    options: &Options,
+) -> Result<Outcome, Box<dyn Error>> {
+    let placeholder = fs::metadata(path).is_ok_and(|meta| meta.len() == 0);
+    if options.offline {
+        return if placeholder {
+            Ok(Outcome::Skipped("empty placeholder"))
+        } else if path.exists() {
+            Ok(Outcome::Skipped("already exists"))
+        } else {
+            write_placeholder(path)
+        };
+    }
+
+    match write_file(path, options.force || placeholder, || {
+        fetch_input(&options.base_url, year, day)
+    }) {
+        Ok(outcome) => Ok(outcome),
+        Err(e) if options.fetch_only => Err(e),
+        Err(e) => {
+            eprintln!("warning: could not fetch the input: {}", e);
+            eprintln!(
+                "  hint: retry later with `aoc setup --year {} --day {} --fetch-only`",
+                year, day
+            );
+            if path.exists() {
+                Ok(Outcome::Skipped("kept as it could not be fetched"))
+            } else {
+                write_placeholder(path)
+            }
+        }
+    }
+}
+
+fn setup_puzzle(
+    year: u32,
+    day: u32,
+    options: &Options,
+    report: &mut Vec<(PathBuf, Outcome)>,
+) -> Result<(), Box<dyn Error>> {
+    // Without a session the page is still there, but only with part 1
+    let session = paths::session();
+    let html = match puzzle::fetch_page(&options.base_url, session.as_deref(), year, day) {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("warning: could not fetch the puzzle description: {}", e);
+            return Ok(());
+        }
+    };
+
+    let path = puzzle_path(year, day);
+    let outcome = write_file(&path, true, || {
+        Ok(puzzle::to_markdown(&html, &options.base_url))
+    })?;
+    report.push((path, outcome));
+
+    if let Some(example) = puzzle::first_code_block(&html) {
+        let path = example_path(year, day, 1);
+        let outcome = write_file(&path, options.force, || Ok(example))?;
+        report.push((path, outcome));
+    }
+    Ok(())
+}
+
+/// Reads `templates/<name>.rs`, listing the available templates if there is
+/// no such file.
+fn load_template(name: &str) -> Result<String, Box<dyn Error>> {
+    let path = paths::templates_dir().join(format!("{}.rs", name));
+    fs::read_to_string(&path).map_err(|e| {
+        let names = template_names().join(", ");
+        format!(
+            "cannot read template {:?} from {:?}: {}. Available templates: {}.",
+            name, path, e, names
+        )
+        .into()
+    })
+}
+
+/// Names of the templates in `templates/`, sorted.
+pub fn template_names() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(paths::templates_dir())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "rs" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Fills in the `{{year}}`, `{{day}}` and `{{title}}` placeholders.
+fn render_template(template: &str, year: u32, day: u32, title: &str) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+        .replace("{{title}}", title)
+}
+
+fn write_placeholder(path: &Path) -> Result<Outcome, Box<dyn Error>> {
+    write_file(path, false, || Ok(String::new()))?;
+    Ok(Outcome::Placeholder)
+}
+
+/// Prints one line per file touched by `setup`.
+pub fn print_report(report: &[(PathBuf, Outcome)]) {
+    for (path, outcome) in report {
+        match outcome {
+            Outcome::Placeholder => println!("  {:<8} {:?} (empty placeholder)", outcome, path),
+            Outcome::Skipped(reason) => println!("  {:<8} {:?} ({})", outcome, path, reason),
+            _ => println!("  {:<8} {:?}", outcome, path),
+        }
+    }
+}
+
+/// Writes the file unless it already exists, or with `force` unless it already
+/// has the same contents. `contents` is only called when the file is written,
+/// so nothing is downloaded for files that are kept.
+fn write_file(
+    path: &Path,
+    force: bool,
+    contents: impl FnOnce() -> Result<String, Box<dyn Error>>,
+) -> Result<Outcome, Box<dyn Error>> {
+    let exists = path.exists();
+    if exists && !force {
+        return Ok(Outcome::Skipped("already exists, use --force to overwrite"));
+    }
+
+    let contents = contents()?;
+    if exists {
+        if fs::read_to_string(path).is_ok_and(|old| old == contents) {
+            return Ok(Outcome::Skipped("unchanged"));
+        }
+    } else if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, contents)?;
+    Ok(if exists {
+        Outcome::Updated
+    } else {
+        Outcome::Created
+    })
+}
+
+/// Downloads the input from adventofcode.com, or another `base_url`.
+fn fetch_input(base_url: &str, year: u32, day: u32) -> Result<String, Box<dyn Error>> {
+    let session = paths::session()
+        .ok_or("no session cookie found. Set AOC_SESSION in .env or session_file in aoc.toml.")?;
+
+    let input_url = format!(
+        "{}/{}/day/{}/input",
+        base_url.trim_end_matches('/'),
+        year,
+        day
+    );
+    let response = reqwest::blocking::Client::new()
+        .get(&input_url)
+        .header("Cookie", format!("session={}", session))
+        .header("User-Agent", USER_AGENT)
+        .send()?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "failed to fetch input for day {}. Status: {}",
+            day,
+            response.status()
+        )
+        .into());
+    }
+
+    Ok(response.text()?)
+}
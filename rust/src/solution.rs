@@ -0,0 +1,88 @@
+use crate::error::{Error, Result};
+use std::any::Any;
+use std::fmt;
+
+/// The output of a day's `parse`, shared by both parts.
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+/// Type-erased entry points of a day, built by the generated registry from the
+/// day module's `parse`, `part1` and `part2` functions.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub parse: fn(&str) -> Result<Parsed>,
+    pub part1: fn(&Parsed) -> Result<Answer>,
+    pub part2: fn(&Parsed) -> Result<Answer>,
+}
+
+impl Solution {
+    /// Solves `part` (1 or 2) on input produced by `self.parse`.
+    pub fn solve(&self, part: u32, parsed: &Parsed) -> Result<Answer> {
+        match part {
+            1 => (self.part1)(parsed),
+            2 => (self.part2)(parsed),
+            part => Err(Error::UnsupportedPart(part)),
+        }
+    }
+}
+
+/// Recovers a day's concrete `Input` from its type-erased parsed value.
+pub fn downcast<T: 'static>(parsed: &Parsed) -> &T {
+    parsed
+        .downcast_ref()
+        .expect("Parsed input passed to the wrong day")
+}
+
+/// The value produced by a single puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// Any integer answer, wide enough for every primitive integer type
+    Number(i128),
+    /// A single-line textual answer
+    Text(String),
+    /// An answer spanning several lines, e.g. letters drawn on a grid
+    Multiline(String),
+    /// Returned by scaffolded parts that have not been solved yet
+    Unimplemented,
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Multiline(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) | Answer::Multiline(s) => write!(f, "{}", s),
+            Answer::Unimplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
@@ -0,0 +1,434 @@
+use crate::http::USER_AGENT;
+use crate::solution::Answer;
+use crate::util::{get_input, load_json, now, save_json};
+use crate::{html, paths, registry};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::Path;
+
+/// The local log of every guess, stored next to `Cargo.toml`
+pub const SUBMISSIONS_FILE: &str = "submissions.json";
+
+/// How adventofcode.com judged a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted too soon after the previous guess; the answer was not checked
+    Throttled,
+    /// The part was already solved on this account
+    AlreadySolved,
+    Unknown,
+}
+
+impl Verdict {
+    /// Whether the answer was actually judged, as opposed to being rejected unseen
+    fn is_judged(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong
+        )
+    }
+}
+
+/// Parsed response page of an answer submission.
+#[derive(Debug, Clone)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// Seconds to wait before the next submission is accepted
+    pub wait: Option<u64>,
+    /// The text of the response article, with markup stripped
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix timestamp of the submission
+    pub submitted_at: u64,
+    /// Unix timestamp before which no further guesses are sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<u64>,
+}
+
+/// Every answer sent so far, used to avoid repeating guesses and to respect throttling.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SubmissionLog {
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// Loads the log from `path`, returning an empty log if the file does not exist yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        load_json(path)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        save_json(path, self)
+    }
+
+    pub fn push(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    /// Returns the reason a guess must not be sent, or `None` if it is fine to submit.
+    pub fn refusal(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+        now: u64,
+    ) -> Option<String> {
+        if let Some(retry_after) = self.submissions.iter().filter_map(|s| s.retry_after).max() {
+            if retry_after > now {
+                return Some(format!(
+                    "Submissions are throttled, try again in {}s",
+                    retry_after - now
+                ));
+            }
+        }
+
+        let previous: Vec<&Submission> = self
+            .submissions
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part && s.verdict.is_judged())
+            .collect();
+
+        if let Some(correct) = previous.iter().find(|s| s.verdict == Verdict::Correct) {
+            return Some(format!(
+                "Part already solved with answer {}",
+                correct.answer
+            ));
+        }
+
+        if let Some(same) = previous.iter().find(|s| s.answer == answer) {
+            return Some(format!(
+                "Answer {} was already submitted and judged {:?}",
+                answer, same.verdict
+            ));
+        }
+
+        // Use earlier too high / too low hints to rule out numeric guesses
+        let value = answer.parse::<i128>().ok()?;
+        for s in &previous {
+            let Ok(guess) = s.answer.parse::<i128>() else {
+                continue;
+            };
+            match s.verdict {
+                Verdict::TooHigh if value >= guess => {
+                    return Some(format!("{} was too high, so {} is too", guess, value));
+                }
+                Verdict::TooLow if value <= guess => {
+                    return Some(format!("{} was too low, so {} is too", guess, value));
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+}
+
+/// Posts an answer and returns the raw HTML of the response page.
+pub fn post_answer(
+    base_url: &str,
+    session: &str,
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: &str,
+) -> reqwest::Result<String> {
+    let url = format!(
+        "{}/{}/day/{}/answer",
+        base_url.trim_end_matches('/'),
+        year,
+        day
+    );
+    let level = part.to_string();
+
+    reqwest::blocking::Client::new()
+        .post(&url)
+        .header("Cookie", format!("session={}", session))
+        .header("User-Agent", USER_AGENT)
+        .form(&[("level", level.as_str()), ("answer", answer)])
+        .send()?
+        .error_for_status()?
+        .text()
+}
+
+/// Interprets the response page of an answer submission.
+pub fn parse_response(html: &str) -> Response {
+    let message = article_text(html);
+    let lower = message.to_lowercase();
+
+    let verdict = if lower.contains("that's the right answer") {
+        Verdict::Correct
+    } else if lower.contains("that's not the right answer") {
+        if lower.contains("too high") {
+            Verdict::TooHigh
+        } else if lower.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if lower.contains("you gave an answer too recently") {
+        Verdict::Throttled
+    } else if lower.contains("you don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown
+    };
+
+    let wait = match verdict {
+        Verdict::Throttled => parse_time_left(&lower),
+        Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong => parse_wait_minutes(&lower),
+        _ => None,
+    };
+
+    Response {
+        verdict,
+        wait,
+        message,
+    }
+}
+
+/// The text of the first `<article>` element, falling back to the whole page.
+fn article_text(html: &str) -> String {
+    html::text(html::articles(html).next().unwrap_or(html))
+}
+
+/// Parses "You have 1m 23s left to wait" into seconds.
+fn parse_time_left(text: &str) -> Option<u64> {
+    // The message also says "you have to wait", so look back from the time left
+    let end = text.find(" left to wait")?;
+    let start = text[..end].rfind("you have ")? + "you have ".len();
+
+    let mut seconds = 0;
+    for token in text[start..end].split_whitespace() {
+        let (value, unit) = token.split_at(token.len() - 1);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(seconds)
+}
+
+/// Parses "please wait one minute / 5 minutes before trying again" into seconds.
+fn parse_wait_minutes(text: &str) -> Option<u64> {
+    let start = text.find("please wait ")? + "please wait ".len();
+    let amount = text[start..].split_whitespace().next()?;
+
+    let minutes = match amount {
+        "one" => 1,
+        "two" => 2,
+        "five" => 5,
+        "ten" => 10,
+        n => n.parse().ok()?,
+    };
+    Some(minutes * 60)
+}
+
+/// Solves a part on the day's input and submits the answer, unless the
+/// submission log shows it would be throttled, repeated or out of bounds.
+pub fn run(year: u32, day: u32, part: u32, base_url: &str) {
+    let session = paths::session().unwrap_or_else(|| {
+        eprintln!("No session cookie found. Set AOC_SESSION in .env or session_file in aoc.toml.");
+        std::process::exit(1);
+    });
+
+    let answer = registry::lookup_solution(year, day, &[part]).and_then(|solution| {
+        let input = get_input(year, day)?;
+        let parsed = (solution.parse)(&input)?;
+        solution.solve(part, &parsed)
+    });
+
+    let answer = match answer.unwrap_or_else(|e| e.exit()) {
+        Answer::Unimplemented => {
+            eprintln!("Part {} is not implemented yet, nothing to submit.", part);
+            std::process::exit(1);
+        }
+        answer => answer.to_string(),
+    };
+    println!("Part {}: {}", part, answer);
+
+    let log_path = paths::crate_dir().join(SUBMISSIONS_FILE);
+    let mut log = SubmissionLog::load(&log_path).unwrap_or_else(|e| {
+        eprintln!("Failed to read {:?}: {}", log_path, e);
+        std::process::exit(1);
+    });
+
+    let now = now();
+    if let Some(reason) = log.refusal(year, day, part, &answer, now) {
+        eprintln!("Not submitting: {}", reason);
+        std::process::exit(1);
+    }
+
+    let html = post_answer(base_url, &session, year, day, part, &answer).unwrap_or_else(|e| {
+        eprintln!("Failed to submit answer: {}", e);
+        std::process::exit(1);
+    });
+    let response = parse_response(&html);
+
+    println!("\n{}", response.message);
+
+    log.push(Submission {
+        year,
+        day,
+        part,
+        answer,
+        verdict: response.verdict.clone(),
+        submitted_at: now,
+        retry_after: response.wait.map(|wait| now + wait),
+    });
+    if let Err(e) = log.save(&log_path) {
+        eprintln!("Failed to write {:?}: {}", log_path, e);
+    }
+
+    match response.verdict {
+        Verdict::Correct => println!("\n✓ Correct!"),
+        verdict => {
+            match response.wait {
+                Some(wait) => eprintln!("\n✗ {:?} (wait {}s before the next guess)", verdict, wait),
+                None => eprintln!("\n✗ {:?}", verdict),
+            }
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RIGHT: &str = r#"<main><article><p>That's the right answer!  You are <span class="day-success">one gold star</span> closer to decorating the North Pole. <a href="/2025/day/1#part2">[Continue to Part Two]</a></p></article></main>"#;
+
+    const TOO_HIGH: &str = r#"<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href="/2025/about">about page</a>, or you can ask for hints on the <a href="https://www.reddit.com/r/adventofcode/" target="_blank">subreddit</a>.  Please wait one minute before trying again. <a href="/2025/day/1">[Return to Day 1]</a></p></article></main>"#;
+
+    const TOO_LOW: &str = r#"<main><article><p>That's not the right answer; your answer is too low.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href="/2025/about">about page</a>, or you can ask for hints on the <a href="https://www.reddit.com/r/adventofcode/" target="_blank">subreddit</a>.  Please wait 5 minutes before trying again. <a href="/2025/day/1">[Return to Day 1]</a></p></article></main>"#;
+
+    const THROTTLED: &str = r#"<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href="/2025/day/1">[Return to Day 1]</a></p></article></main>"#;
+
+    fn submission(answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            year: 2025,
+            day: 1,
+            part: 1,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: 1000,
+            retry_after: None,
+        }
+    }
+
+    fn log(submissions: Vec<Submission>) -> SubmissionLog {
+        SubmissionLog { submissions }
+    }
+
+    #[test]
+    fn parses_right_answer() {
+        let response = parse_response(RIGHT);
+        assert_eq!(response.verdict, Verdict::Correct);
+        assert_eq!(response.wait, None);
+        assert!(response.message.starts_with("That's the right answer!"));
+    }
+
+    #[test]
+    fn parses_too_high_with_wait() {
+        let response = parse_response(TOO_HIGH);
+        assert_eq!(response.verdict, Verdict::TooHigh);
+        assert_eq!(response.wait, Some(60));
+    }
+
+    #[test]
+    fn parses_too_low_with_wait() {
+        let response = parse_response(TOO_LOW);
+        assert_eq!(response.verdict, Verdict::TooLow);
+        assert_eq!(response.wait, Some(300));
+    }
+
+    #[test]
+    fn parses_throttled_with_time_left() {
+        let response = parse_response(THROTTLED);
+        assert_eq!(response.verdict, Verdict::Throttled);
+        assert_eq!(response.wait, Some(83));
+    }
+
+    #[test]
+    fn parses_time_left() {
+        assert_eq!(parse_time_left("you have 1m 23s left to wait."), Some(83));
+        assert_eq!(parse_time_left("you have 45s left to wait."), Some(45));
+        assert_eq!(
+            parse_time_left("you have 1h 2m 3s left to wait."),
+            Some(3723)
+        );
+        assert_eq!(parse_time_left("please wait one minute"), None);
+    }
+
+    #[test]
+    fn parses_wait_minutes() {
+        assert_eq!(
+            parse_wait_minutes("please wait one minute before trying again."),
+            Some(60)
+        );
+        assert_eq!(
+            parse_wait_minutes("please wait 10 minutes before trying again."),
+            Some(600)
+        );
+        assert_eq!(parse_wait_minutes("you have 45s left to wait."), None);
+    }
+
+    #[test]
+    fn refuses_guesses_outside_known_bounds() {
+        let log = log(vec![
+            submission("100", Verdict::TooHigh),
+            submission("50", Verdict::TooLow),
+        ]);
+
+        assert!(log.refusal(2025, 1, 1, "100", 2000).is_some());
+        assert!(log.refusal(2025, 1, 1, "150", 2000).is_some());
+        assert!(log.refusal(2025, 1, 1, "50", 2000).is_some());
+        assert!(log.refusal(2025, 1, 1, "10", 2000).is_some());
+        assert_eq!(log.refusal(2025, 1, 1, "51", 2000), None);
+        assert_eq!(log.refusal(2025, 1, 1, "99", 2000), None);
+
+        // Bounds only apply to the part they were learned on
+        assert_eq!(log.refusal(2025, 1, 2, "150", 2000), None);
+    }
+
+    #[test]
+    fn refuses_repeated_and_solved_guesses() {
+        let guessed = log(vec![
+            submission("abc", Verdict::Wrong),
+            submission("xyz", Verdict::Throttled),
+        ]);
+        assert!(guessed.refusal(2025, 1, 1, "abc", 2000).is_some());
+        // A throttled guess was never judged and may be sent again
+        assert_eq!(guessed.refusal(2025, 1, 1, "xyz", 2000), None);
+
+        let solved = log(vec![submission("42", Verdict::Correct)]);
+        assert!(solved.refusal(2025, 1, 1, "43", 2000).is_some());
+    }
+
+    #[test]
+    fn refuses_guesses_while_throttled() {
+        let mut throttled = submission("1", Verdict::TooLow);
+        throttled.retry_after = Some(1060);
+        let log = log(vec![throttled]);
+
+        assert!(log.refusal(2025, 1, 1, "2", 1059).is_some());
+        assert_eq!(log.refusal(2025, 1, 1, "2", 1060), None);
+    }
+}
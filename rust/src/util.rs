@@ -1,21 +1,192 @@
-#![allow(dead_code)]
-
+use crate::error::{Error, Result};
+use crate::paths::{cache_dir, configured_data_dir, data_dir, input_root, source_dir};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
 use std::fs;
-use std::path::PathBuf;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Once;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Directory holding a day's solution module.
+pub fn day_dir(year: u32, day: u32) -> PathBuf {
+    source_dir().join(year.to_string()).join(day.to_string())
+}
+
+/// Directory holding a day's example inputs, under the data directory.
+pub fn example_dir(year: u32, day: u32) -> PathBuf {
+    data_dir().join(year.to_string()).join(day.to_string())
+}
 
-pub fn get_input(year: u32, day: u32) -> String {
-    let path = PathBuf::from(format!("src/{}/{}/input.txt", year, day));
-    fs::read_to_string(&path).unwrap_or_else(|_| panic!("Failed to read input file at {:?}", path))
+/// Where a day's puzzle input is stored: the input cache, or the configured data directory.
+pub fn input_path(year: u32, day: u32) -> PathBuf {
+    input_root()
+        .join(year.to_string())
+        .join(day.to_string())
+        .join("input.txt")
 }
 
-pub fn get_debug(year: u32, day: u32) -> String {
-    let path = PathBuf::from(format!("src/{}/{}/debug.txt", year, day));
-    fs::read_to_string(&path).unwrap_or_else(|_| panic!("Failed to read debug file at {:?}", path))
+/// Where inputs used to be stored, next to the solution module.
+pub fn legacy_input_path(year: u32, day: u32) -> PathBuf {
+    day_dir(year, day).join("input.txt")
 }
 
-pub fn read_input_lines(year: u32, day: u32) -> Vec<String> {
-    get_input(year, day)
+/// The day's input cached for another account, such as one stored under an
+/// earlier session cookie or while no session was set. Only used to explain
+/// a missing input.
+fn other_account_input(year: u32, day: u32) -> Option<PathBuf> {
+    if configured_data_dir().is_some() {
+        return None;
+    }
+
+    let mut accounts: Vec<PathBuf> = fs::read_dir(cache_dir())
+        .ok()?
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .collect();
+    accounts.sort();
+    accounts
+        .into_iter()
+        .map(|account| {
+            account
+                .join(year.to_string())
+                .join(day.to_string())
+                .join("input.txt")
+        })
+        .find(|path| path.is_file())
+}
+
+/// Reads a day's input, falling back to an input that has not been migrated
+/// out of the source tree yet. The empty placeholder left by an offline
+/// `setup` counts as missing. An input cached for another account is never
+/// read, as its answers would be submitted to the wrong account.
+pub fn get_input(year: u32, day: u32) -> Result<String> {
+    let mut path = input_path(year, day);
+    let legacy = legacy_input_path(year, day);
+
+    if !path.exists() && legacy.is_file() {
+        static WARNED: Once = Once::new();
+        WARNED.call_once(|| {
+            eprintln!(
+                "warning: reading inputs from the source tree, move them to {:?} with `aoc migrate-inputs`",
+                input_root()
+            )
+        });
+        path = legacy;
+    }
+
+    let input = read_file(&path).map_err(|error| match other_account_input(year, day) {
+        Some(other) => Error::MissingInput {
+            path: path.clone(),
+            reason: format!(
+                "not cached for this account, only for another one at {:?}",
+                other
+            ),
+        },
+        None => error,
+    })?;
+    if input.is_empty() {
+        return Err(Error::MissingInput {
+            path,
+            reason: format!(
+                "the file is an empty placeholder, fetch it with `aoc setup --year {} --day {} --fetch-only`",
+                year, day
+            ),
+        });
+    }
+    Ok(input)
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|e| Error::MissingInput {
+        path: path.to_path_buf(),
+        reason: e.to_string(),
+    })
+}
+
+/// Reads puzzle input from an explicit path, or from stdin when the path is `-`.
+pub fn read_input_file(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| Error::MissingInput {
+                path: path.to_path_buf(),
+                reason: e.to_string(),
+            })?;
+        return Ok(input);
+    }
+
+    read_file(path)
+}
+
+/// SHA-256 of an input as lowercase hex, used to tell results on different inputs apart.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Path of an example input: `debug.txt` for example 1, `debug<N>.txt` for the others.
+pub fn example_path(year: u32, day: u32, example: u32) -> PathBuf {
+    let name = match example {
+        0 | 1 => "debug".to_string(),
+        n => format!("debug{}", n),
+    };
+    example_dir(year, day).join(format!("{}.txt", name))
+}
+
+/// Markdown copy of the puzzle description, next to the example inputs.
+pub fn puzzle_path(year: u32, day: u32) -> PathBuf {
+    example_dir(year, day).join("puzzle.md")
+}
+
+pub fn get_example(year: u32, day: u32, example: u32) -> Result<String> {
+    read_file(&example_path(year, day, example))
+}
+
+pub fn get_debug(year: u32, day: u32) -> Result<String> {
+    get_example(year, day, 1)
+}
+
+pub fn read_input_lines(year: u32, day: u32) -> Result<Vec<String>> {
+    Ok(get_input(year, day)?
         .lines()
         .map(|s| s.to_string())
-        .collect()
+        .collect())
+}
+
+/// Splits a line on `separator`, yielding each field with its 1-based column.
+pub fn split_columns(line: &str, separator: char) -> impl Iterator<Item = (usize, &str)> {
+    let mut column = 1;
+    line.split(separator).map(move |field| {
+        let start = column;
+        column += field.len() + separator.len_utf8();
+        (start, field)
+    })
+}
+
+/// Reads a JSON file, returning the default value if the file does not exist yet.
+pub fn load_json<T: DeserializeOwned + Default>(path: &Path) -> io::Result<T> {
+    match fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn save_json<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    let content = serde_json::to_string_pretty(value)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    fs::write(path, content + "\n")
+}
+
+/// Current Unix timestamp in seconds.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
 }
@@ -0,0 +1,181 @@
+use crate::paths::configured_data_dir;
+use crate::util::{day_dir, example_dir, input_path};
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeMap;
+use std::env;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// How long to wait for more file events before re-running, so that a burst of
+/// writes from an editor triggers a single rebuild
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Watches a day's source and input directories and rebuilds and re-runs the
+/// solution whenever something in them changes. Runs until interrupted.
+pub fn watch(year: u32, day: u32, part: Option<u32>) {
+    let mut dirs = vec![day_dir(year, day), example_dir(year, day)];
+    if let Some(input_dir) = input_path(year, day).parent() {
+        dirs.push(input_dir.to_path_buf());
+    }
+    dirs.sort();
+    dirs.dedup();
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).unwrap_or_else(|e| {
+        eprintln!("Failed to start file watcher: {}", e);
+        std::process::exit(1);
+    });
+    for dir in dirs.iter().filter(|dir| dir.is_dir()) {
+        if let Err(e) = watcher.watch(dir, RecursiveMode::Recursive) {
+            eprintln!("Failed to watch {:?}: {}", dir, e);
+            std::process::exit(1);
+        }
+        println!("Watching {:?} for changes", dir);
+    }
+    println!("(Ctrl-C to stop)\n");
+    let mut previous = run(year, day, part);
+
+    loop {
+        let mut changed = match rx.recv() {
+            Ok(Ok(event)) if is_change(&event.kind) => event.paths,
+            Ok(Ok(_)) => continue,
+            Ok(Err(e)) => {
+                eprintln!("Watch error: {}", e);
+                continue;
+            }
+            Err(_) => break,
+        };
+
+        // Let the editor finish writing before rebuilding
+        while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+            if let Ok(event) = event {
+                changed.extend(event.paths);
+            }
+        }
+
+        // Skip editor temp files that were already renamed away
+        let mut names: Vec<String> = changed
+            .iter()
+            .filter(|path| path.exists())
+            .filter_map(|path| path.file_name())
+            .map(|name| name.to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names.dedup();
+        if names.is_empty() {
+            names.push("files".to_string());
+        }
+        println!("\n── {} changed, rebuilding ──\n", names.join(", "));
+
+        let answers = run(year, day, part);
+        print_comparison(&previous, &answers);
+        if !answers.is_empty() {
+            previous = answers;
+        }
+    }
+}
+
+fn is_change(kind: &EventKind) -> bool {
+    matches!(
+        kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    )
+}
+
+/// Rebuilds the runner and solves the day in a child process, echoing its output.
+/// Returns the answers it printed, keyed by part.
+fn run(year: u32, day: u32, part: Option<u32>) -> BTreeMap<u32, String> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+
+    let mut command = Command::new(cargo);
+    command
+        .args(["run", "--quiet", "--bin", "aoc", "--manifest-path"])
+        .arg(manifest);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    if cfg!(feature = "alloc-stats") {
+        command.args(["--features", "alloc-stats"]);
+    }
+    command.args([
+        "--",
+        "run",
+        "--year",
+        &year.to_string(),
+        "--day",
+        &day.to_string(),
+    ]);
+    if let Some(dir) = configured_data_dir() {
+        command.arg("--data-dir").arg(dir);
+    }
+    if let Some(part) = part {
+        command.args(["--part", &part.to_string()]);
+    }
+
+    let output = match command.stderr(Stdio::inherit()).output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run cargo: {}", e);
+            return BTreeMap::new();
+        }
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    print!("{}", stdout);
+    if !output.status.success() {
+        eprintln!("✗ Build or run failed ({})", output.status);
+    }
+
+    parse_answers(&stdout)
+}
+
+/// Picks the `Part N: answer` lines out of the runner's output. Multiline answers
+/// follow a bare `Part N:` line and run until the next blank line.
+fn parse_answers(output: &str) -> BTreeMap<u32, String> {
+    let mut answers = BTreeMap::new();
+    let mut lines = output.lines();
+
+    while let Some(line) = lines.next() {
+        let Some((part, answer)) = line
+            .strip_prefix("Part ")
+            .and_then(|rest| rest.split_once(':'))
+            .and_then(|(part, answer)| Some((part.parse::<u32>().ok()?, answer.trim())))
+        else {
+            continue;
+        };
+
+        let answer = if answer.is_empty() {
+            lines
+                .by_ref()
+                .take_while(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n")
+        } else {
+            answer.to_string()
+        };
+        answers.insert(part, answer);
+    }
+
+    answers
+}
+
+fn print_comparison(previous: &BTreeMap<u32, String>, answers: &BTreeMap<u32, String>) {
+    if previous.is_empty() || answers.is_empty() {
+        return;
+    }
+
+    println!("\nCompared with the previous run:");
+    for (part, answer) in answers {
+        match previous.get(part) {
+            Some(old) if old == answer => println!("  Part {}: unchanged", part),
+            Some(old) if old.contains('\n') || answer.contains('\n') => {
+                println!("  Part {}: changed, was:\n{}", part, old)
+            }
+            Some(old) => println!("  Part {}: {} (was {})", part, answer, old),
+            None => println!("  Part {}: {} (new)", part, answer),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! {{year}} day {{day}}: {{title}}
+
+use crate::error::Result;
+use crate::solution::Answer;
+
+pub type Input = String;
+
+pub fn parse(input: &str) -> Result<Input> {
+    Ok(input.to_string())
+}
+
+#[allow(unused_variables)]
+pub fn part1(input: &Input) -> Result<Answer> {
+    Ok(Answer::Unimplemented)
+}
+
+#[allow(unused_variables)]
+pub fn part2(input: &Input) -> Result<Answer> {
+    Ok(Answer::Unimplemented)
+}
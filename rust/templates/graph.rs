@@ -0,0 +1,30 @@
+//! {{year}} day {{day}}: {{title}}
+
+use crate::error::{Error, Result};
+use crate::solution::Answer;
+use std::collections::HashMap;
+
+/// Adjacency lists, from lines of the form `node: neighbour neighbour ...`
+pub type Input = HashMap<String, Vec<String>>;
+
+pub fn parse(input: &str) -> Result<Input> {
+    let mut graph = HashMap::new();
+    for (i, line) in input.lines().enumerate() {
+        let (node, neighbours) = line
+            .split_once(':')
+            .ok_or_else(|| Error::malformed(i + 1, 1, "expected `node: neighbours`"))?;
+        let neighbours = neighbours.split_whitespace().map(str::to_string).collect();
+        graph.insert(node.trim().to_string(), neighbours);
+    }
+    Ok(graph)
+}
+
+#[allow(unused_variables)]
+pub fn part1(input: &Input) -> Result<Answer> {
+    Ok(Answer::Unimplemented)
+}
+
+#[allow(unused_variables)]
+pub fn part2(input: &Input) -> Result<Answer> {
+    Ok(Answer::Unimplemented)
+}
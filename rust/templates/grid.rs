@@ -0,0 +1,63 @@
+//! {{year}} day {{day}}: {{title}}
+
+use crate::error::{Error, Result};
+use crate::solution::Answer;
+
+/// A rectangular grid of characters, stored row by row
+pub struct Grid {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<u8>,
+}
+
+impl Grid {
+    pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+        (x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+    }
+
+    /// The orthogonal neighbours of a cell that lie inside the grid.
+    pub fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .filter_map(move |(dx, dy)| {
+                let nx = x.checked_add_signed(dx)?;
+                let ny = y.checked_add_signed(dy)?;
+                (nx < self.width && ny < self.height).then_some((nx, ny))
+            })
+    }
+}
+
+pub type Input = Grid;
+
+pub fn parse(input: &str) -> Result<Input> {
+    let lines: Vec<&str> = input.lines().collect();
+    let width = lines.first().map_or(0, |line| line.len());
+
+    let mut cells = Vec::with_capacity(width * lines.len());
+    for (i, line) in lines.iter().enumerate() {
+        if line.len() != width {
+            return Err(Error::malformed(
+                i + 1,
+                line.len().min(width) + 1,
+                format!("expected {} columns, found {}", width, line.len()),
+            ));
+        }
+        cells.extend_from_slice(line.as_bytes());
+    }
+
+    Ok(Grid {
+        width,
+        height: lines.len(),
+        cells,
+    })
+}
+
+#[allow(unused_variables)]
+pub fn part1(input: &Input) -> Result<Answer> {
+    Ok(Answer::Unimplemented)
+}
+
+#[allow(unused_variables)]
+pub fn part2(input: &Input) -> Result<Answer> {
+    Ok(Answer::Unimplemented)
+}
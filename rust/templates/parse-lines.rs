@@ -0,0 +1,32 @@
+//! {{year}} day {{day}}: {{title}}
+
+use crate::error::{parse_field, Result};
+use crate::solution::Answer;
+use crate::util::split_columns;
+
+/// The numbers on one line of the input
+pub type Line = Vec<i64>;
+
+pub type Input = Vec<Line>;
+
+pub fn parse(input: &str) -> Result<Input> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            split_columns(line, ',')
+                .map(|(column, field)| parse_field(i + 1, column, field))
+                .collect()
+        })
+        .collect()
+}
+
+#[allow(unused_variables)]
+pub fn part1(input: &Input) -> Result<Answer> {
+    Ok(Answer::Unimplemented)
+}
+
+#[allow(unused_variables)]
+pub fn part2(input: &Input) -> Result<Answer> {
+    Ok(Answer::Unimplemented)
+}